@@ -0,0 +1,102 @@
+//! finds the solutions in `src/yr<year>/day<day>.rs` and writes `solutions.rs` to `OUT_DIR`,
+//! which declares a module per year with a module per day, and lists them in `DAYS` for `main.rs`.
+//! the day may have leading zeros, other `.rs` files in the year directories are not compiled
+
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    // directories are scanned for changes, so added files are found
+    println!("cargo:rerun-if-changed=src");
+
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    let mut years = read_dir_names(&src)
+        .into_iter()
+        .filter_map(|name| {
+            let year = name.strip_prefix("yr")?.parse::<u16>().ok()?;
+            let mut days = read_dir_names(&src.join(&name))
+                .into_iter()
+                .filter(|file| file.ends_with(".rs"))
+                .filter_map(|file| match parse_day(&file) {
+                    Some(day) => Some((day, file)),
+                    None => {
+                        println!("cargo:warning=src/{name}/{file} is not named day<day>.rs, so it is not compiled");
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+            days.sort_unstable();
+            Some((year, days))
+        })
+        .collect::<Vec<_>>();
+    years.sort_unstable();
+
+    let mut out = String::from("// generated by build.rs from the files in src/\n");
+    for (year, days) in &years {
+        writeln!(out, "\nmod yr{year} {{").unwrap();
+        for (_, file) in days {
+            let path = src.join(format!("yr{year}")).join(file);
+            writeln!(out, "    #[path = {:?}]", path.display().to_string()).unwrap();
+            writeln!(out, "    pub mod {};", module(file)).unwrap();
+        }
+        out.push_str("}\n");
+    }
+
+    let days = years
+        .iter()
+        .flat_map(|(year, days)| days.iter().map(move |(day, file)| (year, day, file)))
+        .collect::<Vec<_>>();
+
+    out.push_str(
+        "\n/// the `SOLUTION` of every `src/yr<year>/day<day>.rs`, sorted by year and day\n",
+    );
+    out.push_str("const DAYS: &[Solution] = &[\n");
+    for (year, _, file) in &days {
+        writeln!(out, "    yr{year}::{}::SOLUTION,", module(file)).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    for (i, (year, day, file)) in days.iter().enumerate() {
+        writeln!(
+            out,
+            "const _: () = assert!(DAYS[{i}].info.year == {year} && DAYS[{i}].info.day == {day}, {:?});",
+            format!("src/yr{year}/{file}: the Info is not for {year} day {day}")
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "const _: () = assert!(!aoc_any::solved_before(DAYS, {i}), {:?});",
+            format!("src/yr{year}/{file}: the year and day in its Info already have a solution")
+        )
+        .unwrap();
+    }
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(dest, out).unwrap();
+}
+
+/// the day of a `day<day>.rs` file name
+fn parse_day(file: &str) -> Option<u8> {
+    let day = file.strip_prefix("day")?.strip_suffix(".rs")?;
+    if day.is_empty() || !day.bytes().all(|it| it.is_ascii_digit()) {
+        return None;
+    }
+    day.parse().ok()
+}
+
+/// the module of a day, named like its file so `day01.rs` and `day1.rs` do not clash
+fn module(file: &str) -> &str {
+    file.strip_suffix(".rs").unwrap_or(file)
+}
+
+/// the names of the entries of a directory, empty if it can not be read
+fn read_dir_names(dir: &Path) -> Vec<String> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|it| it.ok()?.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,127 @@
+//! a global allocator that counts, installed with the `count-allocations` feature.
+//! the counters are shared by all threads, so they are only exact for benchmarks
+//! that do not run next to others, see `--parallel`
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+use serde::{Deserialize, Serialize};
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// the system allocator, counting allocations and live bytes
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    fn allocated(size: usize) {
+        COUNT.fetch_add(1, Relaxed);
+        BYTES.fetch_add(size, Relaxed);
+        let live = LIVE.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(live, Relaxed);
+    }
+}
+
+// SAFETY: every call is forwarded to `System` unchanged
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Relaxed);
+    }
+
+    /// counted as freeing the old block and allocating the new one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+            Self::allocated(new_size);
+        }
+        new
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// what one run allocated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Allocations {
+    pub count: usize,
+    pub bytes: usize,
+    /// the most bytes that were live at once, above what was live before the run
+    pub peak: usize,
+}
+
+/// runs `f` and counts its allocations, `None` without the `count-allocations` feature
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !cfg!(feature = "count-allocations") {
+        return (f(), None);
+    }
+
+    let (count, bytes, live) = (COUNT.load(Relaxed), BYTES.load(Relaxed), LIVE.load(Relaxed));
+    PEAK.store(live, Relaxed);
+    let result = f();
+
+    let allocations = Allocations {
+        count: COUNT.load(Relaxed) - count,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (result, Some(allocations))
+}
+
+/// bytes with a binary unit, like "1.5 KiB"
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[test]
+fn allocations_are_counted() {
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(1536), "1.5 KiB");
+    assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+
+    let (len, allocations) = measure(|| {
+        let kept = vec![0_u8; 4096];
+        drop(vec![0_u8; 1024]);
+        kept.len()
+    });
+    assert_eq!(len, 4096);
+    assert_eq!(allocations.is_some(), cfg!(feature = "count-allocations"));
+    // other test threads allocate and free at the same time, only the totals are certain
+    if let Some(allocations) = allocations {
+        assert!(allocations.count >= 2 && allocations.bytes >= 5120);
+    }
+}
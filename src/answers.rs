@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::types::{BenchRun, ProblemResult};
+
+/// where the expected answers are kept, next to `inputs/`
+const ANSWERS_LOCATION: &str = "./answers.json";
+
+/// the expected answers, as `{year: {day: {label: answer}}}`
+type AnswerMap = BTreeMap<u16, BTreeMap<u8, BTreeMap<String, String>>>;
+
+/// expected answers for the labels of each puzzle, compared with the `Display` of the result
+pub struct Answers {
+    path: PathBuf,
+    map: AnswerMap,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// holds the expected answer
+    Wrong(String),
+    /// there is no expected answer for the label
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong(expected) => write!(f, "wrong, expected {expected}"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Answers {
+    /// loads the answers from `./answers.json`
+    pub fn open() -> anyhow::Result<Self> {
+        Self::load(ANSWERS_LOCATION)
+    }
+
+    pub fn load(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+
+        let map = match fs::read_to_string(&path) {
+            Ok(ser) => serde_json::from_str(&ser)
+                .with_context(|| format!("invalid answers in {}", path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => AnswerMap::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+
+        Ok(Self { path, map })
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn expected(&self, year: u16, day: u8, label: &str) -> Option<&str> {
+        self.map
+            .get(&year)?
+            .get(&day)?
+            .get(label)
+            .map(String::as_str)
+    }
+
+    #[must_use]
+    pub fn verdict(&self, run: &BenchRun) -> Verdict {
+        let Ok(output) = &run.output else {
+            return Verdict::Unknown;
+        };
+
+        match self.expected(run.year, run.day, &run.label) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == output.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(expected.to_owned()),
+        }
+    }
+
+    /// stores the output as the expected answer, returns the one it replaces
+    pub fn accept(
+        &mut self,
+        year: u16,
+        day: u8,
+        label: &str,
+        output: &ProblemResult,
+    ) -> Option<String> {
+        self.map
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .insert(label.to_owned(), output.to_string())
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let ser = serde_json::to_string_pretty(&self.map)?;
+        fs::write(&self.path, ser + "\n")
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+}
+
+#[test]
+fn answers_roundtrip() {
+    let path = std::env::temp_dir().join(format!("aoc-any-answers-{}.json", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.expected(2022, 1, "part1"), None);
+
+    assert_eq!(
+        answers.accept(2022, 1, "part1", &ProblemResult::Number(66_186)),
+        None
+    );
+    answers.save().unwrap();
+
+    let mut answers = Answers::load(&path).unwrap();
+    assert_eq!(answers.expected(2022, 1, "part1"), Some("66186"));
+    assert_eq!(
+        answers.accept(2022, 1, "part1", &ProblemResult::Number(1)),
+        Some("66186".to_owned())
+    );
+
+    fs::remove_file(&path).unwrap();
+}
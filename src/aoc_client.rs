@@ -0,0 +1,284 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use ureq::Agent;
+
+use crate::input_source::FetchError;
+
+/// where requests go when `AOC_BASE_URL` is not set
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// minimum time between two requests to adventofcode.com,
+/// shared between runs through the `LAST_ACCESS` file
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+static LAST_ACCESS: LazyLock<&Path> = LazyLock::new(|| Path::new("./.cache/last_access"));
+
+/// what to do when a request would exceed the request rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Throttle {
+    /// sleep until the next request is allowed
+    #[default]
+    Block,
+    /// fail fast with an error
+    Fail,
+}
+
+impl std::str::FromStr for Throttle {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "block" => Ok(Self::Block),
+            "fail" => Ok(Self::Fail),
+            _ => anyhow::bail!("invalid throttle mode {s:?}, expected `block` or `fail`"),
+        }
+    }
+}
+
+/// the agent with the session cookie from `AOC_TOKEN`, and the request throttling
+/// that downloading inputs and submitting answers share
+pub struct AocClient {
+    /// built on the first request, so a missing token only matters once it is needed
+    agent: Option<Agent>,
+    base_url: String,
+    throttle: Throttle,
+    interval: Duration,
+    /// where the time of the last request is shared with other runs
+    last_access: Option<&'static Path>,
+    last_req: Option<SystemTime>,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(throttle: Throttle) -> Self {
+        Self {
+            agent: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            throttle,
+            interval: MIN_REQUEST_INTERVAL,
+            last_access: Some(*LAST_ACCESS),
+            last_req: None,
+        }
+    }
+
+    /// reads the throttle mode from `AOC_THROTTLE` and the site from `AOC_BASE_URL`
+    pub fn from_env() -> anyhow::Result<Self> {
+        let throttle = match std::env::var("AOC_THROTTLE") {
+            Ok(mode) => mode.parse()?,
+            Err(_) => Throttle::default(),
+        };
+
+        let mut client = Self::new(throttle);
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            base_url
+                .trim_end_matches('/')
+                .clone_into(&mut client.base_url);
+        }
+        Ok(client)
+    }
+
+    /// a client for a local stand-in of the site, without throttling
+    #[cfg(test)]
+    pub(crate) fn local(base_url: &str, token: &str) -> Self {
+        Self {
+            agent: Some(build_agent(base_url, token).unwrap()),
+            base_url: base_url.to_owned(),
+            throttle: Throttle::Fail,
+            interval: Duration::ZERO,
+            last_access: None,
+            last_req: None,
+        }
+    }
+
+    #[must_use]
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// builds the agent if needed, fails if there is no token
+    pub fn ensure_agent(&mut self) -> anyhow::Result<&Agent> {
+        if self.agent.is_none() {
+            let token = std::env::var("AOC_TOKEN")
+                .map_err(|_| FetchError::Unauthenticated)
+                .context("Please set the env-var AOC_TOKEN")?;
+            self.agent = Some(build_agent(&self.base_url, &token)?);
+        }
+
+        Ok(self.agent.as_ref().expect("agent was just built"))
+    }
+
+    /// GETs the path after waiting for a request slot, returns the status and body
+    pub fn get(&mut self, path: &str) -> anyhow::Result<(u16, String)> {
+        let url = self.url(path);
+        self.ensure_agent()?;
+        self.acquire_request_slot()?;
+
+        read_response(self.ensure_agent()?.get(&url).call())
+    }
+
+    /// POSTs the form to the path after waiting for a request slot, returns the status and body
+    pub fn post_form(
+        &mut self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> anyhow::Result<(u16, String)> {
+        let url = self.url(path);
+        self.ensure_agent()?;
+        self.acquire_request_slot()?;
+
+        read_response(self.ensure_agent()?.post(&url).send_form(form))
+    }
+
+    /// opens and exclusively locks `last_access`, `None` for a client without one.
+    /// the lock is released when the file is dropped
+    fn lock_last_access(&self) -> anyhow::Result<Option<File>> {
+        let Some(path) = self.last_access else {
+            return Ok(None);
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .with_context(|| format!("failed to open {}", path.display()))?;
+        file.lock()
+            .with_context(|| format!("failed to lock {}", path.display()))?;
+        Ok(Some(file))
+    }
+
+    /// waits for or checks the request interval depending on the throttle mode,
+    /// then records the upcoming request.
+    /// `last_access` stays locked from the check to the write, so runs started together take turns
+    fn acquire_request_slot(&mut self) -> anyhow::Result<()> {
+        let mut file = self
+            .lock_last_access()
+            .inspect_err(|err| eprintln!("could not open last access, err: {err:#}"))
+            .ok()
+            .flatten();
+
+        let last = file
+            .as_mut()
+            .and_then(|it| read_last_access(it).ok())
+            .max(self.last_req);
+        if let Some(wait) = last.and_then(|it| remaining_wait(self.interval, it, SystemTime::now()))
+        {
+            match self.throttle {
+                Throttle::Block => {
+                    eprintln!("throttling: waiting {wait:.1?} before the next request");
+                    std::thread::sleep(wait);
+                }
+                Throttle::Fail => anyhow::bail!(
+                    "throttled: the next request to adventofcode.com is allowed in {wait:.1?}"
+                ),
+            }
+        }
+
+        let now = SystemTime::now();
+        self.last_req = Some(now);
+        if let Some(file) = &mut file {
+            let _ = write_last_access(file, now)
+                .inspect_err(|err| eprintln!("could not save last access, err: {err}"));
+        }
+
+        Ok(())
+    }
+}
+
+/// the time of the last request made by any run
+fn read_last_access(file: &mut File) -> anyhow::Result<SystemTime> {
+    let mut millis = String::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_string(&mut millis)?;
+    Ok(UNIX_EPOCH + Duration::from_millis(millis.trim().parse()?))
+}
+
+fn write_last_access(file: &mut File, time: SystemTime) -> anyhow::Result<()> {
+    let millis = time.duration_since(UNIX_EPOCH)?.as_millis();
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    Ok(file.write_all(millis.to_string().as_bytes())?)
+}
+
+/// error statuses are returned like successful ones, only transport errors fail
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> anyhow::Result<(u16, String)> {
+    match response {
+        Ok(response) => Ok((response.status(), response.into_string()?)),
+        Err(ureq::Error::Status(status, response)) => {
+            Ok((status, response.into_string().unwrap_or_default()))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn build_agent(base_url: &str, token: &str) -> anyhow::Result<Agent> {
+    let session_cookie = ureq::Cookie::new("session", token.to_owned());
+    let mut cookies = cookie_store::CookieStore::new(None);
+    cookies.insert_raw(&session_cookie, &format!("{base_url}/").parse()?)?;
+
+    Ok(ureq::AgentBuilder::new()
+        .timeout_read(Duration::from_secs(5))
+        .timeout_write(Duration::from_secs(5))
+        .user_agent("github.com/lgoeldner/aoc-any, contact: goeldner.linus@gmail.com")
+        .cookie_store(cookies)
+        .build())
+}
+
+/// `None` if a request made at `last` allows another one at `now`
+fn remaining_wait(interval: Duration, last: SystemTime, now: SystemTime) -> Option<Duration> {
+    // a timestamp in the future counts as a request made just now
+    let elapsed = now.duration_since(last).unwrap_or_default();
+    interval.checked_sub(elapsed).filter(|wait| !wait.is_zero())
+}
+
+#[test]
+fn remaining_wait_works() {
+    let last = UNIX_EPOCH + Duration::from_secs(1_000);
+    let wait = |last, now| remaining_wait(MIN_REQUEST_INTERVAL, last, now);
+
+    assert_eq!(wait(last, last), Some(MIN_REQUEST_INTERVAL));
+    assert_eq!(
+        wait(last, last + Duration::from_secs(2)),
+        MIN_REQUEST_INTERVAL.checked_sub(Duration::from_secs(2))
+    );
+    assert_eq!(wait(last, last + MIN_REQUEST_INTERVAL), None);
+    assert_eq!(
+        wait(last + Duration::from_mins(1), last),
+        Some(MIN_REQUEST_INTERVAL)
+    );
+    assert_eq!(remaining_wait(Duration::ZERO, last, last), None);
+}
+
+#[test]
+fn runs_sharing_last_access_take_turns() {
+    let path = std::env::temp_dir().join(format!("aoc-any-last-access-{}", std::process::id()));
+    let path: &'static Path = Box::leak(path.into_boxed_path());
+
+    let mut handles = Vec::new();
+    for _ in 0..4 {
+        let mut client = AocClient {
+            last_access: Some(path),
+            ..AocClient::new(Throttle::Fail)
+        };
+        handles.push(std::thread::spawn(move || {
+            client.acquire_request_slot().is_ok()
+        }));
+    }
+    let acquired = handles
+        .into_iter()
+        .map(|it| it.join().unwrap())
+        .filter(|it| *it)
+        .count();
+
+    assert_eq!(acquired, 1);
+    assert!(fs::read_to_string(path).unwrap().parse::<u64>().is_ok());
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,437 @@
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use cli_table::{format::Justify, Color, Table, WithTitle};
+use serde::{Deserialize, Serialize};
+
+use crate::stats::Stats;
+use crate::types::BenchRun;
+
+/// every benchmarked entry, one json object per line
+const HISTORY_LOCATION: &str = "./.cache/bench_history.jsonl";
+
+/// what the benchmarks ran on, only runs on the same machine are compared
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Machine {
+    pub hostname: String,
+    pub os: String,
+    pub arch: String,
+    pub cpu: Option<String>,
+    pub threads: usize,
+}
+
+impl Machine {
+    #[must_use]
+    pub fn current() -> Self {
+        let hostname = fs::read_to_string("/etc/hostname")
+            .ok()
+            .or_else(|| std::env::var("HOSTNAME").ok())
+            .or_else(|| std::env::var("COMPUTERNAME").ok())
+            .map_or_else(|| "unknown".to_owned(), |it| it.trim().to_owned());
+
+        let cpu = fs::read_to_string("/proc/cpuinfo").ok().and_then(|info| {
+            info.lines()
+                .find(|line| line.starts_with("model name"))
+                .and_then(|line| line.split_once(':'))
+                .map(|(_, model)| model.trim().to_owned())
+        });
+
+        Self {
+            hostname,
+            os: std::env::consts::OS.to_owned(),
+            arch: std::env::consts::ARCH.to_owned(),
+            cpu,
+            threads: std::thread::available_parallelism().map_or(1, usize::from),
+        }
+    }
+}
+
+/// the commit the benchmarks ran at, and whether there were uncommitted changes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revision {
+    pub commit: String,
+    pub dirty: bool,
+}
+
+impl Revision {
+    /// `None` outside of a git checkout
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        Some(Self {
+            commit: git(&["rev-parse", "HEAD"])?,
+            dirty: git(&["status", "--porcelain"]).is_some_and(|it| !it.is_empty()),
+        })
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRecord {
+    /// when the benchmark run started, in milliseconds since the unix epoch
+    pub run: u64,
+    pub revision: Option<Revision>,
+    pub machine: Machine,
+    pub year: u16,
+    pub day: u8,
+    pub label: String,
+    pub stats: Stats,
+    pub output: String,
+}
+
+/// all benchmark runs recorded so far
+pub struct BenchHistory {
+    path: PathBuf,
+    records: Vec<BenchRecord>,
+}
+
+impl BenchHistory {
+    /// loads the history from `./.cache/bench_history.jsonl`
+    pub fn open() -> anyhow::Result<Self> {
+        Self::load(HISTORY_LOCATION)
+    }
+
+    pub fn load(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+
+        let records = match fs::read_to_string(&path) {
+            Ok(ser) => ser
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()
+                .with_context(|| format!("invalid benchmark in {}", path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+
+        Ok(Self { path, records })
+    }
+
+    /// the records of the runs that did not fail, with the same revision and machine for all of them
+    #[must_use]
+    pub fn records_for(
+        runs: &[BenchRun],
+        revision: Option<&Revision>,
+        machine: &Machine,
+    ) -> Vec<BenchRecord> {
+        let run = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |it| it.as_millis() as u64);
+
+        runs.iter()
+            .filter_map(|it| {
+                Some(BenchRecord {
+                    run,
+                    revision: revision.cloned(),
+                    machine: machine.clone(),
+                    year: it.year,
+                    day: it.day,
+                    label: it.label.clone(),
+                    stats: it.stats(),
+                    output: it.output.as_ref().ok()?.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// appends the records to the history file
+    pub fn append(&mut self, records: Vec<BenchRecord>) -> anyhow::Result<()> {
+        let write = || -> anyhow::Result<()> {
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            for record in &records {
+                writeln!(file, "{}", serde_json::to_string(record)?)?;
+            }
+            Ok(())
+        };
+
+        write().with_context(|| format!("failed to write {}", self.path.display()))?;
+        self.records.extend(records);
+        Ok(())
+    }
+
+    /// the latest record of the entry on the machine
+    #[must_use]
+    pub fn latest(
+        &self,
+        year: u16,
+        day: u8,
+        label: &str,
+        machine: &Machine,
+    ) -> Option<&BenchRecord> {
+        self.records.iter().rev().find(|it| {
+            it.machine == *machine && (it.year, it.day, it.label.as_str()) == (year, day, label)
+        })
+    }
+
+    /// the latest record of each entry on the machine that matches the baseline.
+    ///
+    /// `last` is the latest run of each entry, anything else is resolved
+    /// to a commit by git or used as a prefix of the recorded commits.
+    pub fn baseline(&self, spec: &str, machine: &Machine) -> anyhow::Result<Baseline<'_>> {
+        self.baseline_with(spec, machine, resolve_commit)
+    }
+
+    /// `baseline` with the revisions resolved by `resolve`, `None` uses them as prefixes
+    fn baseline_with(
+        &self,
+        spec: &str,
+        machine: &Machine,
+        resolve: impl FnOnce(&str) -> Option<String>,
+    ) -> anyhow::Result<Baseline<'_>> {
+        let commit = match spec {
+            "last" => None,
+            rev => Some(resolve(rev).unwrap_or_else(|| rev.to_owned())),
+        };
+
+        let records = self
+            .records
+            .iter()
+            .filter(|it| it.machine == *machine)
+            .filter(|it| {
+                commit.as_deref().is_none_or(|commit| {
+                    it.revision
+                        .as_ref()
+                        .is_some_and(|rev| rev.commit.starts_with(commit))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if records.is_empty() {
+            anyhow::bail!(
+                "no benchmarks on this machine ({}) recorded for the baseline {spec:?}",
+                machine.hostname
+            );
+        }
+
+        Ok(Baseline { records })
+    }
+}
+
+/// the commit a revision like `HEAD~1` or a tag names, `None` if git does not know it
+fn resolve_commit(rev: &str) -> Option<String> {
+    git(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("{rev}^{{commit}}"),
+    ])
+}
+
+pub struct Baseline<'a> {
+    records: Vec<&'a BenchRecord>,
+}
+
+impl Baseline<'_> {
+    fn get(&self, year: u16, day: u8, label: &str) -> Option<&BenchRecord> {
+        self.records
+            .iter()
+            .rev()
+            .find(|it| (it.year, it.day, it.label.as_str()) == (year, day, label))
+            .copied()
+    }
+
+    /// compares the medians of the runs with the baseline,
+    /// changes within `threshold` percent count as unchanged
+    #[must_use]
+    pub fn compare(&self, runs: &[BenchRun], threshold: f64) -> Vec<Comparison> {
+        runs.iter()
+            .filter(|run| run.output.is_ok())
+            .filter_map(|run| {
+                let baseline = self.get(run.year, run.day, &run.label)?.stats.median;
+                let change = percent_change(baseline, run.median);
+
+                Some(Comparison {
+                    year: run.year,
+                    day: run.day,
+                    label: run.label.clone(),
+                    baseline,
+                    current: run.median,
+                    change,
+                    status: if change > threshold {
+                        Change::Slower
+                    } else if change < -threshold {
+                        Change::Faster
+                    } else {
+                        Change::Unchanged
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+fn percent_change(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_secs_f64();
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.as_secs_f64() - baseline) / baseline * 100.0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Slower,
+    Faster,
+    Unchanged,
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Slower => write!(f, "regression"),
+            Self::Faster => write!(f, "faster"),
+            Self::Unchanged => write!(f, "~"),
+        }
+    }
+}
+
+#[derive(Table)]
+pub struct Comparison {
+    #[table(title = "year", justify = "Justify::Right")]
+    pub year: u16,
+    #[table(title = "day")]
+    pub day: u8,
+    #[table(title = "label")]
+    pub label: String,
+    #[table(title = "baseline median", display_fn = "display_duration")]
+    pub baseline: Duration,
+    #[table(title = "median", display_fn = "display_duration")]
+    pub current: Duration,
+    #[table(
+        title = "change",
+        display_fn = "display_change",
+        justify = "Justify::Right"
+    )]
+    pub change: f64,
+    #[table(title = "status", color = "Color::Yellow")]
+    pub status: Change,
+}
+
+fn display_duration(inp: &Duration) -> impl std::fmt::Display {
+    format!("{inp:?}")
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn display_change(inp: &f64) -> impl std::fmt::Display {
+    format!("{inp:+.1}%")
+}
+
+/// prints the comparisons, returns how many of them regressed
+pub fn print_comparisons(comparisons: &[Comparison], to_stderr: bool) -> anyhow::Result<usize> {
+    let regressions = comparisons
+        .iter()
+        .filter(|it| it.status == Change::Slower)
+        .count();
+
+    if comparisons.is_empty() {
+        eprintln!("none of the benchmarks are in the baseline");
+    } else if to_stderr {
+        cli_table::print_stderr(comparisons.with_title())
+            .map_err(|_| anyhow!("Failed to print table"))?;
+    } else {
+        cli_table::print_stdout(comparisons.with_title())
+            .map_err(|_| anyhow!("Failed to print table"))?;
+    }
+    Ok(regressions)
+}
+
+#[test]
+fn baseline_compares_medians() {
+    let path = std::env::temp_dir().join(format!("aoc-any-bench-{}.jsonl", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let machine = Machine::current();
+    let info = crate::Info {
+        name: "test",
+        day: 1,
+        year: 2022,
+        bench: crate::BenchTimes::Default,
+    };
+    let run = |label: &str, micros| {
+        BenchRun::new(
+            &info,
+            label.to_owned(),
+            crate::ProblemResult::Number(1),
+            &Stats::once(Duration::from_micros(micros)),
+            Duration::from_micros(micros),
+        )
+    };
+    let revision = |commit: &str| Revision {
+        commit: commit.to_owned(),
+        dirty: false,
+    };
+
+    let mut history = BenchHistory::load(&path).unwrap();
+    assert!(history.baseline("last", &machine).is_err());
+
+    history
+        .append(BenchHistory::records_for(
+            &[run("part1", 100), run("part2", 100)],
+            Some(&revision("aaaa")),
+            &machine,
+        ))
+        .unwrap();
+    history
+        .append(BenchHistory::records_for(
+            &[run("part1", 200)],
+            Some(&revision("bbbb")),
+            &machine,
+        ))
+        .unwrap();
+
+    let history = BenchHistory::load(&path).unwrap();
+    let current = [run("part1", 210), run("part2", 150), run("other", 1)];
+
+    let last = history
+        .baseline("last", &machine)
+        .unwrap()
+        .compare(&current, 5.0);
+    assert_eq!(
+        last.iter().map(|it| it.status).collect::<Vec<_>>(),
+        [Change::Unchanged, Change::Slower]
+    );
+
+    let first = history
+        .baseline_with("aaaa", &machine, |_| None)
+        .unwrap()
+        .compare(&current, 5.0);
+    assert_eq!(first[0].status, Change::Slower);
+    assert!((first[0].change - 110.0).abs() < 1e-9);
+
+    let faster = history
+        .baseline_with("bb", &machine, |_| None)
+        .unwrap()
+        .compare(&[run("part1", 100)], 5.0);
+    assert_eq!(faster[0].status, Change::Faster);
+
+    assert!(history.baseline_with("cccc", &machine, |_| None).is_err());
+
+    // runs on another machine, even with the same hostname, are not compared
+    let other = Machine {
+        threads: machine.threads + 1,
+        ..machine
+    };
+    assert!(history.baseline("last", &other).is_err());
+
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,117 @@
+use anyhow::{anyhow, Context};
+use cli_table::{format::Justify, Color, Table, WithTitle};
+
+use crate::cli::CacheAction;
+use crate::{format_utc, InputCache};
+
+#[derive(Table)]
+struct CacheRow {
+    #[table(title = "year", justify = "Justify::Right")]
+    year: u16,
+    #[table(title = "day")]
+    day: u8,
+    #[table(title = "bytes", justify = "Justify::Right")]
+    size: u64,
+    #[table(title = "fetched (UTC)")]
+    fetched: String,
+    #[table(title = "status", color = "Color::Yellow")]
+    status: String,
+}
+
+/// handles the `cache` subcommand
+pub fn run(cache: &mut InputCache, action: &CacheAction) -> anyhow::Result<()> {
+    match action {
+        CacheAction::List => list(cache),
+        CacheAction::Evict { dates } => {
+            for date in dates {
+                let (year, day) = parse_date(date)?;
+                if cache.evict(year, day)? {
+                    eprintln!("evicted {year} day {day}");
+                } else {
+                    eprintln!("{year} day {day} was not cached");
+                }
+            }
+            Ok(())
+        }
+        CacheAction::Verify { refetch } => verify(cache, *refetch),
+        CacheAction::Import { date, file } => {
+            let (year, day) = parse_date(date)?;
+            cache.import(year, day, file)?;
+            eprintln!("imported {} as {year} day {day}", file.display());
+            Ok(())
+        }
+    }
+}
+
+fn list(cache: &InputCache) -> anyhow::Result<()> {
+    let rows = cache
+        .entries()?
+        .into_iter()
+        .map(|entry| CacheRow {
+            year: entry.year,
+            day: entry.day,
+            size: entry.size,
+            fetched: format_utc(entry.fetched),
+            status: entry
+                .problem
+                .map_or_else(|| "ok".to_owned(), |it| it.to_string()),
+        })
+        .collect::<Vec<_>>();
+
+    cli_table::print_stdout(rows.with_title()).map_err(|_| anyhow!("Failed to print table"))
+}
+
+/// reports cached inputs that look like error pages, optionally fetching them again
+fn verify(cache: &mut InputCache, refetch: bool) -> anyhow::Result<()> {
+    let broken = cache
+        .entries()?
+        .into_iter()
+        .filter_map(|entry| Some((entry.year, entry.day, entry.problem?)))
+        .collect::<Vec<_>>();
+
+    if broken.is_empty() {
+        eprintln!("all cached inputs look fine");
+        return Ok(());
+    }
+
+    for (year, day, problem) in broken {
+        eprintln!("{year} day {day}: {problem}");
+
+        if refetch {
+            match cache.refetch(year, day) {
+                Ok(_) => eprintln!("  refetched"),
+                Err(err) => eprintln!("  failed to refetch: {err:#}"),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// parses `<year>/<day>`, e.g. `2022/14`
+fn parse_date(date: &str) -> anyhow::Result<(u16, u8)> {
+    let (year, day) = date
+        .split_once('/')
+        .with_context(|| format!("expected <year>/<day>, got {date:?}"))?;
+
+    let year = year
+        .parse()
+        .with_context(|| format!("invalid year in {date:?}"))?;
+    let day = day
+        .parse()
+        .with_context(|| format!("invalid day in {date:?}"))?;
+
+    if !(1..=25).contains(&day) {
+        anyhow::bail!("day {day} is not between 1 and 25");
+    }
+
+    Ok((year, day))
+}
+
+#[test]
+fn parse_date_works() {
+    assert_eq!(parse_date("2022/14").unwrap(), (2022, 14));
+    assert!(parse_date("2022-14").is_err());
+    assert!(parse_date("2022/26").is_err());
+    assert!(parse_date("22x/1").is_err());
+}
@@ -0,0 +1,209 @@
+//! the `calendar` subcommand, which days of each year are solved, verified and benchmarked
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use cli_table::{format::Justify, Color, Table, WithTitle};
+
+use crate::answers::Answers;
+use crate::bench_history::{BenchHistory, Machine};
+use crate::types::Solution;
+
+#[derive(Table)]
+struct CalendarRow {
+    #[table(title = "year", justify = "Justify::Right")]
+    year: u16,
+    #[table(title = "day", justify = "Justify::Right")]
+    day: u8,
+    #[table(title = "name")]
+    name: &'static str,
+    #[table(title = "part 1", color = "Color::Green")]
+    part1: Part,
+    #[table(title = "part 2", color = "Color::Green")]
+    part2: Part,
+}
+
+/// how far a part got
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Part {
+    solved: bool,
+    /// the output of the latest benchmark on this machine against the expected answer
+    check: Check,
+    /// the median of the latest benchmark on this machine
+    median: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Check {
+    /// there is no benchmark or no expected answer
+    #[default]
+    Unknown,
+    Correct,
+    Wrong,
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.solved, self.check) {
+            (false, _) => write!(f, "-")?,
+            (true, Check::Unknown) => write!(f, "solved")?,
+            (true, Check::Correct) => write!(f, "verified")?,
+            (true, Check::Wrong) => write!(f, "wrong")?,
+        }
+        match self.median {
+            Some(median) if self.solved => write!(f, ", {median:?}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// all 25 days of the years that have solutions, or of `years` if there are any
+fn rows(
+    days: &'static [Solution],
+    years: &[u16],
+    answers: &Answers,
+    history: &BenchHistory,
+    machine: &Machine,
+) -> Vec<CalendarRow> {
+    let mut shown = if years.is_empty() {
+        days.iter().map(|it| it.info.year).collect::<Vec<_>>()
+    } else {
+        years.to_vec()
+    };
+    shown.sort_unstable();
+    shown.dedup();
+
+    let part = |year, day, label, solved| {
+        let latest = history.latest(year, day, label, machine);
+        Part {
+            solved,
+            check: match (latest, answers.expected(year, day, label)) {
+                (Some(latest), Some(expected)) if latest.output == expected => Check::Correct,
+                (Some(_), Some(_)) => Check::Wrong,
+                _ => Check::Unknown,
+            },
+            median: latest.map(|it| it.stats.median),
+        }
+    };
+
+    shown
+        .into_iter()
+        .flat_map(|year| (1..=25).map(move |day| (year, day)))
+        .map(|(year, day)| {
+            let solution = days
+                .iter()
+                .find(|it| (it.info.year, it.info.day) == (year, day));
+            CalendarRow {
+                year,
+                day,
+                name: solution.map_or("", |it| it.info.name),
+                part1: part(year, day, "part1", solution.is_some()),
+                part2: part(
+                    year,
+                    day,
+                    "part2",
+                    solution.is_some_and(|it| it.part2.is_some()),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// prints the calendar of each year, with a summary of it
+pub fn print(days: &'static [Solution], years: &[u16], answers: &Answers) -> anyhow::Result<()> {
+    let history = BenchHistory::open()?;
+    let rows = rows(days, years, answers, &history, &Machine::current());
+    if rows.is_empty() {
+        anyhow::bail!("there are no solutions yet");
+    }
+
+    cli_table::print_stdout(rows.with_title()).map_err(|_| anyhow!("Failed to print table"))?;
+
+    let mut years = rows.iter().map(|it| it.year).collect::<Vec<_>>();
+    years.dedup();
+    for year in years {
+        let parts = rows
+            .iter()
+            .filter(|it| it.year == year)
+            .flat_map(|it| [it.part1, it.part2])
+            .filter(|it| it.solved)
+            .collect::<Vec<_>>();
+        let days = rows
+            .iter()
+            .filter(|it| it.year == year && it.part1.solved)
+            .count();
+
+        println!(
+            "{year}: {days}/25 days, {}/50 parts solved, {} verified, {} benchmarked",
+            parts.len(),
+            parts.iter().filter(|it| it.check == Check::Correct).count(),
+            parts.iter().filter(|it| it.median.is_some()).count(),
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn calendar_shows_every_day() {
+    use crate::types::{BenchTimes, Info, ProblemResult};
+
+    const DAYS: &[Solution] = &[Solution {
+        part1: |_| Ok(ProblemResult::Number(1)),
+        part2: None,
+        parse: None,
+        info: Info {
+            name: "a",
+            day: 3,
+            year: 2016,
+            bench: BenchTimes::Default,
+        },
+        params: &[],
+        examples: &[],
+        other: &[],
+    }];
+
+    let dir = std::env::temp_dir().join(format!("aoc-any-calendar-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let mut answers = Answers::load(dir.join("answers.json")).unwrap();
+    answers.accept(2016, 3, "part1", &ProblemResult::Number(1));
+    let mut history = BenchHistory::load(dir.join("history.jsonl")).unwrap();
+    let machine = Machine::current();
+
+    // an expected answer alone does not verify the part, its latest benchmark does
+    let solved = rows(DAYS, &[], &answers, &history, &machine);
+    assert_eq!(solved.len(), 25);
+    assert_eq!(solved[2].name, "a");
+    assert_eq!(solved[2].part1.to_string(), "solved");
+
+    let run = |output: i64| {
+        let stats = crate::stats::Stats::once(Duration::from_micros(1));
+        crate::types::BenchRun::new(
+            &DAYS[0].info,
+            "part1".to_owned(),
+            output.into(),
+            &stats,
+            stats.mean,
+        )
+    };
+    history
+        .append(BenchHistory::records_for(&[run(2)], None, &machine))
+        .unwrap();
+    let solved = rows(DAYS, &[], &answers, &history, &machine);
+    assert_eq!(solved[2].part1.to_string(), "wrong, 1µs");
+
+    history
+        .append(BenchHistory::records_for(&[run(1)], None, &machine))
+        .unwrap();
+    let solved = rows(DAYS, &[], &answers, &history, &machine);
+    assert_eq!(solved[2].part1.to_string(), "verified, 1µs");
+    assert_eq!(solved[2].part2.to_string(), "-");
+    assert_eq!(solved[3].part1.to_string(), "-");
+
+    let years = rows(DAYS, &[2015, 2016], &answers, &history, &machine);
+    assert_eq!(
+        (years.len(), years[0].year, years[25].year),
+        (50, 2015, 2016)
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
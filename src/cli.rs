@@ -0,0 +1,331 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::output::Format;
+
+/// Runs and benchmarks Advent of Code solutions.
+///
+/// Without a subcommand, the arguments are the same as for `bench`.
+#[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub bench: RunArgs,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Runs the selected solutions once and prints their results
+    Run(RunArgs),
+    /// Benchmarks the selected solutions
+    Bench(RunArgs),
+    /// Lists the solutions a selection matches without running them
+    List(Selection),
+    /// Downloads the inputs of the selected days into the cache
+    Fetch(Selection),
+    /// Runs the selected solutions once and stores their results as the expected answers
+    Accept(Selection),
+    /// Runs the selected part once and submits its result
+    Submit(Selection),
+    /// Runs the selected solutions on the examples of their days and checks the answers
+    Test(TestArgs),
+    /// Rebuilds and reruns the selected solutions and their examples whenever
+    /// their source, input or example files change, and shows what changed
+    Watch(Selection),
+    /// Creates `src/yr<year>/day<day>.rs` from the template, with an example input file,
+    /// and downloads the input
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Shows which days of each year are solved, verified and benchmarked
+    Calendar {
+        /// Only these years, also the ones without solutions
+        #[arg(long)]
+        year: Vec<u16>,
+    },
+    /// Inspects and manages the cached inputs
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Runs the selected entries for `--isolate` and prints them for the parent process
+    #[command(hide = true)]
+    Child {
+        /// Benchmarks the entries instead of running them once
+        #[arg(long)]
+        bench: bool,
+        #[command(flatten)]
+        args: RunArgs,
+    },
+}
+
+/// which solutions to use.
+///
+/// Queries can select any solution, without queries only the ones
+/// that run by default (part 1, part 2 and `Run::Yes`) are selected.
+#[derive(Args, Debug, Default, Clone)]
+pub struct Selection {
+    /// Fuzzy queries matched against names like "2022 day08: part1", any of them may match.
+    /// "day8" is read as "day08"
+    pub queries: Vec<String>,
+
+    /// Only solutions of these years
+    #[arg(long)]
+    pub year: Vec<u16>,
+
+    /// Only solutions of these days
+    #[arg(long)]
+    pub day: Vec<u8>,
+
+    /// Only this part: `1`, `2` or the label of another solution, like "recursive part2"
+    #[arg(long)]
+    pub part: Option<String>,
+
+    /// Queries have to equal the name or the label instead of fuzzy matching
+    #[arg(long, conflicts_with = "prefix")]
+    pub exact: bool,
+
+    /// Queries have to be a prefix of the name, of the name without the year or of the label
+    #[arg(long)]
+    pub prefix: bool,
+
+    /// Only the best match of each query, and entries sharing its score
+    #[arg(long)]
+    pub best: bool,
+}
+
+impl Selection {
+    /// the arguments that select the same solutions again
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = self.queries.clone();
+        for year in &self.year {
+            args.extend(["--year".to_owned(), year.to_string()]);
+        }
+        for day in &self.day {
+            args.extend(["--day".to_owned(), day.to_string()]);
+        }
+        if let Some(part) = &self.part {
+            args.extend(["--part".to_owned(), part.clone()]);
+        }
+        for (flag, set) in [
+            ("--exact", self.exact),
+            ("--prefix", self.prefix),
+            ("--best", self.best),
+        ] {
+            if set {
+                args.push(flag.to_owned());
+            }
+        }
+        args
+    }
+}
+
+#[derive(Args, Debug, Default)]
+pub struct TestArgs {
+    #[command(flatten)]
+    pub select: Selection,
+
+    /// How the results are printed
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+#[derive(Args, Debug, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub select: Selection,
+
+    /// Reads the input from this file instead of the input cache
+    #[arg(long, conflicts_with = "stdin")]
+    pub input: Option<PathBuf>,
+
+    /// Reads the input from stdin instead of the input cache
+    #[arg(long)]
+    pub stdin: bool,
+
+    /// Overrides a puzzle parameter, like `--param row=10` for 2022 day 15.
+    /// The results are not checked or recorded, like with `--input`
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    pub params: Vec<(String, String)>,
+
+    /// How the results are printed
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+
+    /// Samples every solution until its mean is stable, instead of a fixed number of times.
+    /// Days that are benchmarked once or not at all are not repeated
+    #[arg(long)]
+    pub adaptive: bool,
+
+    /// Compares the medians with a baseline from the benchmark history, `last` or a git revision,
+    /// and fails on regressions
+    #[arg(long, value_name = "BASELINE")]
+    pub compare: Option<String>,
+
+    /// How many percent slower than the baseline counts as a regression
+    #[arg(long, default_value_t = 5.0, value_name = "PERCENT")]
+    pub threshold: f64,
+
+    /// Does not add the results to the benchmark history
+    #[arg(long)]
+    pub no_record: bool,
+
+    /// Prints what would run instead of running it
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Benchmarks the entries concurrently instead of one after another,
+    /// faster, but their timings interfere with each other
+    #[arg(long)]
+    pub parallel: bool,
+
+    /// How many threads the rayon pool of the solutions has, all cores by default
+    #[arg(long, value_name = "N")]
+    pub threads: Option<usize>,
+
+    /// Pins the benchmarks to this core
+    #[arg(long, value_name = "CORE", conflicts_with = "parallel")]
+    pub pin: Option<usize>,
+
+    /// Runs every entry in its own process, one after another,
+    /// so hangs, crashes and running out of memory fail only that entry
+    #[arg(long)]
+    pub isolate: bool,
+
+    /// Kills isolated entries that run longer than this, 60 seconds by default.
+    /// Implies `--isolate`
+    #[arg(long, value_name = "SECS", value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+
+    /// Limits the address space of isolated entries. Implies `--isolate`
+    #[arg(
+        long,
+        value_name = "MB",
+        // passed to `ulimit -v` in kilobytes, which have to fit in a u64
+        value_parser = clap::value_parser!(u64).range(1..=u64::MAX / 1024)
+    )]
+    pub memory_limit: Option<u64>,
+}
+
+impl RunArgs {
+    #[must_use]
+    pub fn params(&self) -> Vec<(&str, &str)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect()
+    }
+
+    /// whether the entries run in child processes
+    #[must_use]
+    pub const fn isolated(&self) -> bool {
+        self.isolate || self.timeout.is_some() || self.memory_limit.is_some()
+    }
+}
+
+fn parse_param(param: &str) -> Result<(String, String), String> {
+    param
+        .split_once('=')
+        .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+        .ok_or_else(|| format!("expected NAME=VALUE, got {param:?}"))
+}
+
+fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    let secs = secs
+        .parse::<f64>()
+        .map_err(|err| format!("expected seconds, {err}"))?;
+    match Duration::try_from_secs_f64(secs) {
+        Ok(timeout) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!("expected a positive number of seconds, got {secs}")),
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CacheAction {
+    /// Lists the cached inputs with their size and status
+    List,
+    /// Removes cached inputs
+    Evict {
+        /// Days as `<year>/<day>`, e.g. `2022/14`
+        #[arg(required = true)]
+        dates: Vec<String>,
+    },
+    /// Reports cached inputs that look like error pages
+    Verify {
+        /// Downloads the reported inputs again
+        #[arg(long)]
+        refetch: bool,
+    },
+    /// Caches a file as the input of a day
+    Import {
+        /// The day as `<year>/<day>`, e.g. `2022/14`
+        date: String,
+        file: PathBuf,
+    },
+}
+
+#[test]
+fn cli_parses() {
+    use clap::CommandFactory;
+
+    Cli::command().debug_assert();
+
+    let cli = Cli::parse_from(["aoc-any", "day14"]);
+    assert!(cli.command.is_none());
+    assert_eq!(cli.bench.select.queries, ["day14"]);
+
+    let cli = Cli::parse_from([
+        "aoc-any", "run", "--year", "2022", "--day", "8", "--day", "9", "--part", "2", "--exact",
+        "a", "b",
+    ]);
+    let Some(Command::Run(args)) = cli.command else {
+        panic!("expected the run command");
+    };
+    assert_eq!(args.select.year, [2022]);
+    assert_eq!(args.select.day, [8, 9]);
+    assert_eq!(args.select.part.as_deref(), Some("2"));
+    assert!(args.select.exact);
+    assert_eq!(args.select.queries, ["a", "b"]);
+
+    assert!(Cli::try_parse_from(["aoc-any", "run", "--input", "x", "--stdin"]).is_err());
+
+    let mut reparsed = vec!["aoc-any".to_owned(), "list".to_owned()];
+    reparsed.extend(args.select.to_args());
+    let Some(Command::List(select)) = Cli::parse_from(reparsed).command else {
+        panic!("expected the list command");
+    };
+    assert_eq!(select.to_args(), args.select.to_args());
+    assert_eq!((select.day, select.exact), (args.select.day, true));
+
+    let cli = Cli::parse_from(["aoc-any", "--param", "row=10", "--param", "origin=500,0"]);
+    assert_eq!(cli.bench.params(), [("row", "10"), ("origin", "500,0")]);
+    assert!(Cli::try_parse_from(["aoc-any", "--param", "row"]).is_err());
+
+    let cli = Cli::parse_from(["aoc-any", "run", "--timeout", "2.5"]);
+    let Some(Command::Run(args)) = cli.command else {
+        panic!("expected the run command");
+    };
+    assert!(args.isolated() && !args.isolate);
+    assert_eq!(args.timeout, Some(Duration::from_millis(2500)));
+    for timeout in ["-1", "0", "nan", "inf"] {
+        let arg = format!("--timeout={timeout}");
+        assert!(Cli::try_parse_from(["aoc-any", "run", &arg]).is_err());
+    }
+    for memory_limit in ["0", &u64::MAX.to_string()] {
+        let arg = format!("--memory-limit={memory_limit}");
+        assert!(Cli::try_parse_from(["aoc-any", "run", &arg]).is_err());
+    }
+    let max = format!("--memory-limit={}", u64::MAX / 1024);
+    assert!(Cli::try_parse_from(["aoc-any", "run", &max]).is_ok());
+    assert!(!Cli::parse_from(["aoc-any"]).bench.isolated());
+    assert!(Cli::try_parse_from(["aoc-any", "--parallel", "--pin", "0"]).is_err());
+}
@@ -0,0 +1,77 @@
+//! the examples from the puzzle texts, checked by the `test` command and `cargo test`
+
+use crate::answers::Verdict;
+use crate::select::{self, Entry};
+use crate::types::{BenchRun, Solution};
+use crate::{catch_panic, parse_input, run_solution};
+
+/// an input from the puzzle text, with the answers the puzzle gives for it
+#[derive(Debug)]
+pub struct Example {
+    /// tells the examples of a day apart, like "example" or "larger example"
+    pub name: &'static str,
+    pub input: &'static str,
+    /// the expected answer of each label, like `("part1", "13140")`.
+    /// labels without an answer are not run on this example
+    pub answers: &'static [(&'static str, &'static str)],
+    /// overrides of the parameters the `Solution` declares, see `Input::param`
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+impl Example {
+    #[must_use]
+    pub fn answer(&self, label: &str) -> Option<&'static str> {
+        self.answers
+            .iter()
+            .find(|(it, _)| *it == label)
+            .map(|(_, answer)| *answer)
+    }
+}
+
+/// runs each entry on the examples that have an answer for its label.
+/// the runs are labeled like "part1 @ example", their verdict is set
+pub fn run(entries: &[Entry]) -> Vec<BenchRun> {
+    entries
+        .iter()
+        .flat_map(|entry| {
+            entry.examples.iter().filter_map(move |example| {
+                let expected = example.answer(entry.label)?;
+                let label = format!("{} @ {}", entry.label, example.name);
+
+                let mut run = catch_panic(|| {
+                    let parsed = parse_input(example.input, entry.parse)?;
+                    let params = entry.params(example.params);
+                    let label = label.clone();
+                    run_solution(example.input, &params, &parsed, entry.info, label, entry.f)
+                })
+                .unwrap_or_else(|err| BenchRun::failed(entry.info, label, err));
+
+                run.verdict = match &run.output {
+                    Ok(output) if output.to_string() == expected => Verdict::Correct,
+                    _ => Verdict::Wrong(expected.to_owned()),
+                };
+                Some(run)
+            })
+        })
+        .collect()
+}
+
+/// runs all examples of the days, including the ones of entries that do not run by default.
+/// fails with the examples that fail or are wrong
+pub fn check(days: &'static [Solution]) -> anyhow::Result<()> {
+    let runs = run(&days.iter().flat_map(select::entries).collect::<Vec<_>>());
+
+    let wrong = runs
+        .iter()
+        .filter(|it| it.verdict != Verdict::Correct)
+        .map(|it| match &it.output {
+            Ok(output) => format!("{} day {}: {}, got {output}", it.year, it.day, it.label),
+            Err(err) => format!("{} day {}: {}, {err:#}", it.year, it.day, it.label),
+        })
+        .collect::<Vec<_>>();
+
+    if !wrong.is_empty() {
+        anyhow::bail!("{} example(s) failed:\n{}", wrong.len(), wrong.join("\n"));
+    }
+    Ok(())
+}
@@ -1,118 +1,336 @@
-use std::cell::RefCell;
-use std::fs;
-use std::path::Path;
-use std::time::Duration;
+use std::fs::{self, File};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::SystemTime;
 
 use anyhow::Context;
 use gxhash::GxHashMap;
-use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
-use ureq::Agent;
 
+use crate::input_source::{sources_from_spec, suspicious_input, FetchError, InputSource};
 use crate::types::DateProvider;
 
+/// the order sources are consulted in when `AOC_INPUT_SOURCES` is not set
+const DEFAULT_SOURCES: &str = "local,web";
+
 pub struct InputCache {
-    map: GxHashMap<(u16, u8), String>,
-    agent: Agent,
-    last_req: Option<std::time::SystemTime>,
+    dir: CacheDir,
+    sources: Vec<Box<dyn InputSource>>,
 }
 
 impl InputCache {
-    fn retrieve_last_access() -> anyhow::Result<std::time::SystemTime> {
-        todo!()
-    }
-
-    fn try_throttle(&self) -> anyhow::Result<()> {
-        todo!()
-    }
+    /// Creates an `InputCache` with the sources listed in `AOC_INPUT_SOURCES`,
+    /// `"local,web"` by default, or only the cache with `AOC_INPUT_SOURCES=offline`
+    ///
+    /// # Errors
+    /// - `AOC_INPUT_SOURCES` or `AOC_THROTTLE` are invalid
+    /// - the inputs from the old `aoc_input.json` could not be migrated
+    pub fn new() -> anyhow::Result<Self> {
+        // the .env file is optional, `AOC_TOKEN` is only needed to download inputs
+        let _ = dotenvy::dotenv();
 
-    fn throttle(&self) {}
+        let spec = std::env::var("AOC_INPUT_SOURCES").unwrap_or_else(|_| DEFAULT_SOURCES.into());
 
-    pub fn new() -> anyhow::Result<Self> {
-        dotenvy::dotenv()?;
-
-        let session_cookie = ureq::Cookie::new(
-            "session",
-            std::env::var("AOC_TOKEN").context("Please set the env-var AOC_TOKEN")?,
-        );
-        let mut cookies = cookie_store::CookieStore::new(None);
-        cookies.insert_raw(&session_cookie, &"https://adventofcode.com/".parse()?)?;
-
-        let agent: Agent = ureq::AgentBuilder::new()
-            .timeout_read(Duration::from_secs(5))
-            .timeout_write(Duration::from_secs(5))
-            .user_agent("github.com/lgoeldner/aoc-any, contact: goeldner.linus@gmail.com")
-            .cookie_store(cookies)
-            .build();
+        let dir = CacheDir::new(*CACHE_LOCATION);
+        dir.migrate_legacy(*LEGACY_CACHE_LOCATION)
+            .context("failed to migrate the old input cache")?;
 
         Ok(Self {
-            map: Self::retrieve_local_cache().unwrap_or_default(),
-            agent,
-            last_req: None,
+            dir,
+            sources: sources_from_spec(&spec).context("invalid AOC_INPUT_SOURCES")?,
         })
     }
 
-    fn get_web_input(&self, day: &dyn DateProvider) -> anyhow::Result<String> {
-        let url = format!(
-            "https://adventofcode.com/{}/day/{}/input",
-            day.year(),
-            day.day(),
-        );
+    /// whether only cached inputs are available, like with `AOC_INPUT_SOURCES=offline`
+    #[must_use]
+    pub fn is_offline(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// where the input of a day is cached
+    #[must_use]
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.path(year, day)
+    }
 
-        Ok(self.agent.get(&url).call()?.into_string()?)
+    /// replaces the sources consulted on a cache miss, in order
+    pub fn set_sources(&mut self, sources: Vec<Box<dyn InputSource>>) {
+        self.sources = sources;
     }
 
     pub fn get(&mut self, solution: &dyn DateProvider) -> Result<String, anyhow::Error> {
-        let self_ = RefCell::new(self);
+        let (year, day) = solution.get_datetuple();
+
+        if let Some(res) = self.dir.read(year, day)? {
+            return Ok(res);
+        }
 
-        if let Some(res) = self_.borrow().map.get(&solution.get_datetuple()) {
-            return Ok(res.clone());
+        // only one process fetches a missing input, the others wait and read its result
+        let _lock = self.dir.lock(year, day)?;
+        if let Some(res) = self.dir.read(year, day)? {
+            return Ok(res);
         }
 
-        let value = self_.borrow().get_web_input(solution)?;
-        //  let value = input;
+        let (input, cacheable) = self.fetch(year, day)?;
+        if cacheable {
+            self.dir.write(year, day, &input)?;
+        }
+        Ok(input)
+    }
+
+    /// the input from the first source that has it, and whether that source is cacheable
+    fn fetch(&mut self, year: u16, day: u8) -> anyhow::Result<(String, bool)> {
+        let mut errors = Vec::new();
 
-        self_
-            .borrow_mut()
-            .map
-            .insert(solution.get_datetuple(), value.clone());
+        for source in &mut self.sources {
+            match source.fetch(year, day) {
+                Ok(Some(value)) => return Ok((value, source.cacheable())),
+                Ok(None) => {}
+                Err(err) => errors.push(err.context(format!("{} source failed", source.name()))),
+            }
+        }
+
+        if let Some(err) = errors.into_iter().next() {
+            return Err(err.context(format!("could not get input for {year} day {day}")));
+        }
 
-        Ok(value)
+        let names = self.sources.iter().map(|it| it.name()).collect::<Vec<_>>();
+        anyhow::bail!("no input for {year} day {day} in the cache or sources {names:?}")
     }
 
-    fn retrieve_local_cache() -> Option<GxHashMap<(u16, u8), String>> {
-        if let Ok(ser) = std::fs::read_to_string(*CACHE_LOCATION) {
-            Some(serde_json::from_str::<SerdeMap>(&ser).ok()?.0)
+    /// all cached inputs, sorted by date
+    pub fn entries(&self) -> anyhow::Result<Vec<CacheEntry>> {
+        self.dir.entries()
+    }
+
+    /// removes a cached input, returns whether there was one
+    pub fn evict(&self, year: u16, day: u8) -> anyhow::Result<bool> {
+        let _lock = self.dir.lock(year, day)?;
+        self.dir.remove(year, day)
+    }
+
+    /// gets the input from the sources again and replaces the cached one with it.
+    /// the cached input is kept if fetching fails or the new input does not look like one
+    pub fn refetch(&mut self, year: u16, day: u8) -> anyhow::Result<String> {
+        let _lock = self.dir.lock(year, day)?;
+        let (input, cacheable) = self.fetch(year, day)?;
+        if let Some(problem) = suspicious_input(&input) {
+            anyhow::bail!("the fetched input looks wrong, kept the cached one: {problem}");
+        }
+
+        // an uncached source is read on every get, unless a stale cached copy hides it
+        if cacheable {
+            self.dir.write(year, day, &input)?;
         } else {
-            None
+            self.dir.remove(year, day)?;
         }
+        Ok(input)
+    }
+
+    /// caches the contents of `path` as the input for `year` and `day`,
+    /// replacing what is cached
+    pub fn import(&self, year: u16, day: u8, path: &Path) -> anyhow::Result<()> {
+        let input = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        let _lock = self.dir.lock(year, day)?;
+        self.dir.write(year, day, &input)
     }
 }
 
-static CACHE_LOCATION: Lazy<&Path> = Lazy::new(|| Path::new("./.cache/aoc_input.json"));
-static LAST_ACCESS: Lazy<&Path> = Lazy::new(|| Path::new("./.cache/last_access"));
+/// a cached input as listed by `InputCache::entries`
+pub struct CacheEntry {
+    pub year: u16,
+    pub day: u8,
+    pub size: u64,
+    /// when the input was written to the cache
+    pub fetched: SystemTime,
+    /// why the input looks like it is not a real puzzle input
+    pub problem: Option<FetchError>,
+}
 
-#[serde_as]
-#[derive(Serialize, Deserialize)]
-struct SerdeMap(#[serde_as(as = "Vec<(_, _)>")] GxHashMap<(u16, u8), String>);
+static CACHE_LOCATION: LazyLock<&Path> = LazyLock::new(|| Path::new("./.cache/inputs"));
+/// the single json file inputs used to be cached in
+static LEGACY_CACHE_LOCATION: LazyLock<&Path> =
+    LazyLock::new(|| Path::new("./.cache/aoc_input.json"));
+
+/// one file per puzzle at `{root}/{year}/day{NN}.txt`
+struct CacheDir {
+    root: PathBuf,
+}
+
+impl CacheDir {
+    fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
 
-impl Drop for InputCache {
-    fn drop(&mut self) {
-        let serde_map = &SerdeMap(std::mem::take(&mut self.map));
+    fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day{day:0>2}.txt"))
+    }
 
-        if let Some(parent) = CACHE_LOCATION.parent() {
-            let _ = fs::create_dir_all(parent);
+    fn read(&self, year: u16, day: u8) -> anyhow::Result<Option<String>> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
         }
+    }
+
+    /// writes to a temporary file first and renames it,
+    /// so readers never see a partially written input
+    fn write(&self, year: u16, day: u8, input: &str) -> anyhow::Result<()> {
+        let path = self.path(year, day);
+        let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+
+        let write = || -> std::io::Result<()> {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            let mut file = File::create(&tmp)?;
+            file.write_all(input.as_bytes())?;
+            file.sync_all()?;
 
-        if let Ok(ser) = serde_json::to_string(serde_map) {
-            let _ = std::fs::write(*CACHE_LOCATION, ser)
-                .inspect_err(|err| eprintln!("could not save map, err: {err}"));
+            fs::rename(&tmp, &path)
+        };
+
+        write()
+            .inspect_err(|_| drop(fs::remove_file(&tmp)))
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    fn remove(&self, year: u16, day: u8) -> anyhow::Result<bool> {
+        let path = self.path(year, day);
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err).with_context(|| format!("failed to remove {}", path.display())),
         }
     }
+
+    fn entries(&self) -> anyhow::Result<Vec<CacheEntry>> {
+        let year_dirs = match fs::read_dir(&self.root) {
+            Ok(dirs) => dirs,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut entries = Vec::new();
+
+        for year_dir in year_dirs {
+            let year_dir = year_dir?;
+            let Ok(year) = year_dir.file_name().to_string_lossy().parse::<u16>() else {
+                continue;
+            };
+
+            for file in fs::read_dir(year_dir.path())? {
+                let file = file?;
+                // skips lock and temporary files
+                let name = file.file_name().to_string_lossy().into_owned();
+                let Some(Ok(day)) = name
+                    .strip_prefix("day")
+                    .and_then(|it| it.strip_suffix(".txt"))
+                    .map(str::parse::<u8>)
+                else {
+                    continue;
+                };
+
+                let metadata = file.metadata()?;
+                let input = fs::read_to_string(file.path())?;
+
+                entries.push(CacheEntry {
+                    year,
+                    day,
+                    size: metadata.len(),
+                    fetched: metadata.modified()?,
+                    problem: suspicious_input(&input),
+                });
+            }
+        }
+
+        entries.sort_unstable_by_key(|it| (it.year, it.day));
+        Ok(entries)
+    }
+
+    /// takes an exclusive lock on the entry, released when the returned file is dropped
+    fn lock(&self, year: u16, day: u8) -> anyhow::Result<File> {
+        let path = self.path(year, day).with_extension("lock");
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = File::create(&path)
+            .with_context(|| format!("failed to create lock file {}", path.display()))?;
+        file.lock()
+            .with_context(|| format!("failed to lock {}", path.display()))?;
+
+        Ok(file)
+    }
+
+    /// moves the inputs of the old single-file cache into this one,
+    /// then renames the old file so this only happens once
+    fn migrate_legacy(&self, legacy: &Path) -> anyhow::Result<()> {
+        let ser = match fs::read_to_string(legacy) {
+            Ok(ser) => ser,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let SerdeMap(map) = serde_json::from_str(&ser)?;
+
+        for ((year, day), input) in map {
+            let _lock = self.lock(year, day)?;
+            if self.read(year, day)?.is_none() {
+                self.write(year, day, &input)?;
+            }
+        }
+
+        fs::rename(legacy, legacy.with_extension("json.migrated"))?;
+        Ok(())
+    }
 }
 
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+struct SerdeMap(#[serde_as(as = "Vec<(_, _)>")] GxHashMap<(u16, u8), String>);
+
 #[test]
+fn cache_dir_migrates_legacy_json() {
+    let root = std::env::temp_dir().join(format!("aoc-any-cache-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let dir = CacheDir::new(root.join("inputs"));
+
+    dir.write(2022, 3, "already cached").unwrap();
+
+    let legacy = root.join("aoc_input.json");
+    let map = GxHashMap::from_iter([
+        ((2022, 1), "1000\n2000\n".to_owned()),
+        ((2022, 3), "outdated".to_owned()),
+    ]);
+    fs::write(&legacy, serde_json::to_string(&SerdeMap(map)).unwrap()).unwrap();
+
+    dir.migrate_legacy(&legacy).unwrap();
+
+    assert_eq!(dir.read(2022, 1).unwrap().as_deref(), Some("1000\n2000\n"));
+    assert_eq!(
+        dir.read(2022, 3).unwrap().as_deref(),
+        Some("already cached")
+    );
+    assert_eq!(dir.read(2022, 2).unwrap(), None);
+    assert!(!legacy.exists());
+    assert!(root.join("aoc_input.json.migrated").exists());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+#[ignore = "needs AOC_TOKEN and network access"]
 fn test() {
     let mut cache = InputCache::new().unwrap();
     let day = crate::Solution {
@@ -122,10 +340,44 @@ fn test() {
             year: 2022,
             bench: crate::types::BenchTimes::Default,
         },
+        params: &[],
+        examples: &[],
         other: &[],
         part1: |_| todo!(),
         part2: Some(|_| todo!()),
+        parse: None,
     };
     let inp = cache.get(&day).unwrap();
     println!("{inp}");
 }
+
+#[test]
+fn refetch_keeps_the_cached_input_on_failure() {
+    struct Fixed(&'static str);
+    impl InputSource for Fixed {
+        fn name(&self) -> &'static str {
+            "fixed"
+        }
+
+        fn fetch(&mut self, _year: u16, _day: u8) -> anyhow::Result<Option<String>> {
+            Ok(Some(self.0.to_owned()))
+        }
+    }
+
+    let root = std::env::temp_dir().join(format!("aoc-any-refetch-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let mut cache = InputCache {
+        dir: CacheDir::new(root.clone()),
+        sources: vec![Box::new(Fixed("<!DOCTYPE html>"))],
+    };
+    cache.dir.write(2022, 1, "1000\n").unwrap();
+
+    assert!(cache.refetch(2022, 1).is_err());
+    assert_eq!(cache.dir.read(2022, 1).unwrap().as_deref(), Some("1000\n"));
+
+    cache.set_sources(vec![Box::new(Fixed("2000\n"))]);
+    assert_eq!(cache.refetch(2022, 1).unwrap(), "2000\n");
+    assert_eq!(cache.dir.read(2022, 1).unwrap().as_deref(), Some("2000\n"));
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,284 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+use crate::aoc_client::{AocClient, Throttle};
+
+/// somewhere the `InputCache` can load an input from when it is not cached yet
+pub trait InputSource: Send {
+    /// short name used in error messages
+    fn name(&self) -> &'static str;
+
+    /// returns `Ok(None)` if the source does not have the input
+    fn fetch(&mut self, year: u16, day: u8) -> anyhow::Result<Option<String>>;
+
+    /// whether fetched inputs are kept in the cache,
+    /// sources that are cheap to read again can opt out so edits to them show up
+    fn cacheable(&self) -> bool {
+        true
+    }
+}
+
+/// builds the sources listed in a comma separated `spec`, in order.
+///
+/// `local` reads from `./inputs`, `web` downloads from adventofcode.com,
+/// `offline` (or an empty spec) leaves only the cache.
+pub fn sources_from_spec(spec: &str) -> anyhow::Result<Vec<Box<dyn InputSource>>> {
+    if spec.trim() == "offline" {
+        return Ok(Vec::new());
+    }
+
+    spec.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| -> anyhow::Result<Box<dyn InputSource>> {
+            match name {
+                "local" => Ok(Box::new(LocalDir::new("./inputs"))),
+                "web" => Ok(Box::new(WebSource::from_env()?)),
+                _ => anyhow::bail!(
+                    "unknown input source {name:?}, expected `local`, `web` or `offline`"
+                ),
+            }
+        })
+        .collect()
+}
+
+/// a directory of `{year}-day{NN}.txt` files
+pub struct LocalDir {
+    dir: PathBuf,
+}
+
+impl LocalDir {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(format!("{year}-day{day:0>2}.txt"))
+    }
+}
+
+impl InputSource for LocalDir {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    fn cacheable(&self) -> bool {
+        false
+    }
+
+    fn fetch(&mut self, year: u16, day: u8) -> anyhow::Result<Option<String>> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+}
+
+/// why adventofcode.com did not respond with a puzzle input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchError {
+    /// the session in `AOC_TOKEN` is missing, invalid or expired
+    Unauthenticated,
+    /// the puzzle has not been released yet
+    NotYetUnlocked,
+    RateLimited,
+    ServerError(u16),
+    UnexpectedStatus(u16),
+    /// the request succeeded, but the body is not an input
+    InvalidBody(&'static str),
+}
+
+impl FetchError {
+    /// whether every following request would fail the same way
+    #[must_use]
+    pub const fn is_persistent(self) -> bool {
+        matches!(self, Self::Unauthenticated | Self::RateLimited)
+    }
+
+    /// classifies a response, `Ok` if the body is a real input
+    pub fn check_response(status: u16, body: &str) -> Result<(), Self> {
+        match status {
+            200 => suspicious_input(body).map_or(Ok(()), Err),
+            400 | 401 | 403 => Err(Self::Unauthenticated),
+            404 => Err(Self::NotYetUnlocked),
+            429 => Err(Self::RateLimited),
+            500..=599 => Err(Self::ServerError(status)),
+            _ => Err(Self::UnexpectedStatus(status)),
+        }
+    }
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unauthenticated => write!(
+                f,
+                "not logged in, the session in AOC_TOKEN is missing, invalid or expired"
+            ),
+            Self::NotYetUnlocked => write!(f, "the puzzle is not unlocked yet"),
+            Self::RateLimited => write!(f, "rate limited by adventofcode.com, try again later"),
+            Self::ServerError(status) => {
+                write!(f, "adventofcode.com had a server error ({status})")
+            }
+            Self::UnexpectedStatus(status) => write!(f, "unexpected response status {status}"),
+            Self::InvalidBody(what) => write!(f, "the response is not a puzzle input: {what}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// checks for the pages adventofcode.com responds with instead of an input
+#[must_use]
+pub fn suspicious_input(input: &str) -> Option<FetchError> {
+    const MARKERS: &[(&str, FetchError)] = &[
+        (
+            "Please don't repeatedly request this endpoint before it unlocks",
+            FetchError::NotYetUnlocked,
+        ),
+        ("Puzzle inputs differ by user", FetchError::Unauthenticated),
+        ("404 Not Found", FetchError::InvalidBody("not found page")),
+        (
+            "500 Internal Server Error",
+            FetchError::InvalidBody("server error page"),
+        ),
+    ];
+
+    let start = input.trim_start();
+
+    if start.is_empty() {
+        return Some(FetchError::InvalidBody("empty"));
+    }
+
+    if let Some((_, problem)) = MARKERS.iter().find(|(marker, _)| start.contains(marker)) {
+        return Some(*problem);
+    }
+
+    let lowercase_start = start.get(..15).unwrap_or(start).to_ascii_lowercase();
+    if lowercase_start.starts_with("<!doctype html") || lowercase_start.starts_with("<html") {
+        return Some(FetchError::InvalidBody("html page"));
+    }
+
+    None
+}
+
+/// downloads inputs from adventofcode.com with the session in `AOC_TOKEN`
+pub struct WebSource {
+    client: AocClient,
+    /// set after an error that all further requests would run into as well
+    failed: Option<FetchError>,
+}
+
+impl WebSource {
+    #[must_use]
+    pub fn new(throttle: Throttle) -> Self {
+        Self::with_client(AocClient::new(throttle))
+    }
+
+    #[must_use]
+    pub const fn with_client(client: AocClient) -> Self {
+        Self {
+            client,
+            failed: None,
+        }
+    }
+
+    /// configures the client from `AOC_THROTTLE` and `AOC_BASE_URL`
+    pub fn from_env() -> anyhow::Result<Self> {
+        Ok(Self::with_client(AocClient::from_env()?))
+    }
+}
+
+impl InputSource for WebSource {
+    fn name(&self) -> &'static str {
+        "web"
+    }
+
+    fn fetch(&mut self, year: u16, day: u8) -> anyhow::Result<Option<String>> {
+        let path = format!("/{year}/day/{day}/input");
+
+        if let Some(err) = self.failed {
+            return Err(err).context("skipped request after an earlier failure");
+        }
+
+        let (status, body) = self.client.get(&path)?;
+
+        FetchError::check_response(status, &body)
+            .inspect_err(|err| {
+                if err.is_persistent() {
+                    self.failed = Some(*err);
+                }
+            })
+            .with_context(|| format!("GET {} responded with {status}", self.client.url(&path)))?;
+
+        Ok(Some(body))
+    }
+}
+
+#[test]
+fn check_response_works() {
+    const INPUT: &str = "1000\n2000\n\n3000\n";
+
+    assert_eq!(FetchError::check_response(200, INPUT), Ok(()));
+    assert_eq!(
+        FetchError::check_response(
+            200,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+        ),
+        Err(FetchError::Unauthenticated)
+    );
+    assert_eq!(
+        FetchError::check_response(200, "<!DOCTYPE html>\n<html lang=\"en-us\">"),
+        Err(FetchError::InvalidBody("html page"))
+    );
+    assert_eq!(
+        FetchError::check_response(200, " \n"),
+        Err(FetchError::InvalidBody("empty"))
+    );
+    assert_eq!(
+        FetchError::check_response(400, "Puzzle inputs differ by user."),
+        Err(FetchError::Unauthenticated)
+    );
+    assert_eq!(
+        FetchError::check_response(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!"
+        ),
+        Err(FetchError::NotYetUnlocked)
+    );
+    assert_eq!(
+        FetchError::check_response(429, ""),
+        Err(FetchError::RateLimited)
+    );
+    assert_eq!(
+        FetchError::check_response(503, INPUT),
+        Err(FetchError::ServerError(503))
+    );
+    assert_eq!(
+        FetchError::check_response(302, ""),
+        Err(FetchError::UnexpectedStatus(302))
+    );
+}
+
+#[test]
+fn sources_from_spec_works() {
+    let names = |spec| {
+        sources_from_spec(spec)
+            .unwrap()
+            .iter()
+            .map(|source| source.name())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names("local,web"), ["local", "web"]);
+    assert_eq!(names(" web , local "), ["web", "local"]);
+    assert!(names("offline").is_empty());
+    assert!(names("").is_empty());
+    assert!(sources_from_spec("local,ftp").is_err());
+}
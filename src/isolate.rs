@@ -0,0 +1,356 @@
+//! runs each entry in a child process of the same binary, so hangs, crashes
+//! and running out of memory fail only that entry
+
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::allocations::Allocations;
+use crate::cli::RunArgs;
+use crate::select::Entry;
+use crate::stats::Stats;
+use crate::types::{BenchRun, ProblemResult};
+
+/// how long an entry may run with `--isolate` and without `--timeout`
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_mins(1);
+/// how often a child is checked for having exited
+const POLL: Duration = Duration::from_millis(5);
+
+/// a `ProblemResult` as the child sends it, `Other` arrives as its `Debug` text
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Output {
+    Number(i64),
+    Big(i128),
+    Text(String),
+    Lines(Vec<String>),
+    Pair(Box<(Self, Self)>),
+    Unit,
+}
+
+impl From<&ProblemResult> for Output {
+    fn from(result: &ProblemResult) -> Self {
+        match result {
+            ProblemResult::Number(n) => Self::Number(*n),
+            ProblemResult::Big(n) => Self::Big(*n),
+            ProblemResult::Text(text) => Self::Text(text.clone()),
+            ProblemResult::Lines(lines) => Self::Lines(lines.clone()),
+            ProblemResult::Pair(pair) => Self::Pair(Box::new(((&pair.0).into(), (&pair.1).into()))),
+            ProblemResult::Unit => Self::Unit,
+            other @ ProblemResult::Other(_) => Self::Text(other.to_string()),
+        }
+    }
+}
+
+impl From<Output> for ProblemResult {
+    fn from(output: Output) -> Self {
+        match output {
+            Output::Number(n) => Self::Number(n),
+            Output::Big(n) => Self::Big(n),
+            Output::Text(text) => Self::Text(text),
+            Output::Lines(lines) => Self::Lines(lines),
+            Output::Pair(pair) => Self::Pair(Box::new((pair.0.into(), pair.1.into()))),
+            Output::Unit => Self::Unit,
+        }
+    }
+}
+
+/// a `BenchRun` as the child prints it, one json object per line
+#[derive(Serialize, Deserialize)]
+struct ChildRun {
+    label: String,
+    /// mean, median, min, max, stddev and p95
+    stats: [u64; 6],
+    samples: usize,
+    outliers: usize,
+    parse_ns: Option<u64>,
+    solve_ns: u64,
+    elapsed_ns: u64,
+    allocations: Option<Allocations>,
+    output: Result<Output, String>,
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+impl From<&BenchRun> for ChildRun {
+    fn from(run: &BenchRun) -> Self {
+        let stats = run.stats();
+        Self {
+            label: run.label.clone(),
+            stats: [
+                stats.mean,
+                stats.median,
+                stats.min,
+                stats.max,
+                stats.stddev,
+                stats.p95,
+            ]
+            .map(nanos),
+            samples: stats.samples,
+            outliers: stats.outliers,
+            parse_ns: run.parse_time.map(nanos),
+            solve_ns: nanos(run.solve_time),
+            elapsed_ns: nanos(run.elapsed),
+            allocations: run.allocations(),
+            output: match &run.output {
+                Ok(output) => Ok(output.into()),
+                Err(err) => Err(format!("{err:#}")),
+            },
+        }
+    }
+}
+
+impl ChildRun {
+    fn into_run(self, entry: &Entry) -> BenchRun {
+        let [mean, median, min, max, stddev, p95] = self.stats.map(Duration::from_nanos);
+        let stats = Stats {
+            samples: self.samples,
+            mean,
+            median,
+            min,
+            max,
+            stddev,
+            p95,
+            outliers: self.outliers,
+        };
+
+        match self.output {
+            Ok(output) => BenchRun::new(
+                entry.info,
+                self.label,
+                output.into(),
+                &stats,
+                Duration::from_nanos(self.elapsed_ns),
+            )
+            .with_phases(
+                self.parse_ns.map(Duration::from_nanos),
+                Duration::from_nanos(self.solve_ns),
+            )
+            .with_allocations(self.allocations),
+            Err(err) => BenchRun::failed(entry.info, self.label, anyhow::anyhow!(err)),
+        }
+    }
+}
+
+/// prints the runs for the parent, in the child
+pub fn print_runs(runs: &[BenchRun]) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    for run in runs {
+        serde_json::to_writer(&mut stdout, &ChildRun::from(run))?;
+        writeln!(stdout)?;
+    }
+    Ok(())
+}
+
+/// runs the entries one after another, each in a child process that gets its input on stdin.
+/// benchmarks them with `bench`, runs them once otherwise
+pub fn run(inputs: &[(String, Entry)], args: &RunArgs, bench: bool) -> Vec<BenchRun> {
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(err) => {
+            let err = anyhow::Error::new(err).context("failed to find the running binary");
+            return inputs
+                .iter()
+                .map(|(_, entry)| {
+                    BenchRun::failed(
+                        entry.info,
+                        entry.label.to_owned(),
+                        anyhow::anyhow!("{err:#}"),
+                    )
+                })
+                .collect();
+        }
+    };
+
+    inputs
+        .iter()
+        .map(|(input, entry)| {
+            run_child(&exe, input, entry, args, bench)
+                .unwrap_or_else(|err| BenchRun::failed(entry.info, entry.label.to_owned(), err))
+        })
+        .collect()
+}
+
+fn run_child(
+    exe: &Path,
+    input: &str,
+    entry: &Entry,
+    args: &RunArgs,
+    bench: bool,
+) -> anyhow::Result<BenchRun> {
+    let mut child_args = vec!["child".to_owned()];
+    if bench {
+        child_args.push("--bench".to_owned());
+    }
+    if args.adaptive {
+        child_args.push("--adaptive".to_owned());
+    }
+    for (flag, value) in [("--threads", args.threads), ("--pin", args.pin)] {
+        if let Some(value) = value {
+            child_args.extend([flag.to_owned(), value.to_string()]);
+        }
+    }
+    for (name, value) in &args.params {
+        child_args.extend(["--param".to_owned(), format!("{name}={value}")]);
+    }
+    child_args.extend([
+        "--stdin".to_owned(),
+        "--exact".to_owned(),
+        entry.name.clone(),
+    ]);
+
+    // the address space limit is set by a shell that then becomes the child
+    let mut command = args.memory_limit.map_or_else(
+        || Command::new(exe),
+        |megabytes| {
+            let mut shell = Command::new("sh");
+            shell
+                .args(["-c", "ulimit -v \"$0\" && exec \"$@\""])
+                .arg((megabytes * 1024).to_string())
+                .arg(exe);
+            shell
+        },
+    );
+    let mut child = command
+        .args(&child_args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("failed to start {}", exe.display()))?;
+
+    // the pipes are drained while waiting, so a chatty child can not block on them
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = input.to_owned();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let stdout = drain(child.stdout.take().expect("stdout is piped"));
+    let stderr = drain(child.stderr.take().expect("stderr is piped"));
+
+    let timeout = args.timeout.unwrap_or(DEFAULT_TIMEOUT);
+    let status = wait_timeout(&mut child, timeout)?;
+
+    // a child that died early closes stdin, which is reported by its status instead
+    let _ = writer.join();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    eprint!("{stderr}");
+
+    let Some(status) = status else {
+        anyhow::bail!("timed out after {timeout:?}");
+    };
+    if !status.success() {
+        anyhow::bail!("{}", crash_reason(status, &stderr, args.memory_limit));
+    }
+
+    // solutions may print to stdout as well, the run is the last line
+    let line = stdout
+        .lines()
+        .rev()
+        .find(|it| !it.trim().is_empty())
+        .context("the child printed no result")?;
+    let run = serde_json::from_str::<ChildRun>(line)
+        .with_context(|| format!("the child printed an invalid result: {line}"))?;
+    Ok(run.into_run(entry))
+}
+
+fn drain(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        let _ = pipe.read_to_string(&mut text);
+        text
+    })
+}
+
+/// waits for the child to exit, kills it after `timeout` and returns `None`
+fn wait_timeout(child: &mut Child, timeout: Duration) -> anyhow::Result<Option<ExitStatus>> {
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL);
+    }
+}
+
+/// why a child exited unsuccessfully, from its status and what it printed
+fn crash_reason(status: ExitStatus, stderr: &str, memory_limit: Option<u64>) -> String {
+    if stderr.contains("memory allocation of") {
+        return memory_limit.map_or_else(
+            || "ran out of memory".to_owned(),
+            |megabytes| format!("ran out of memory, the limit is {megabytes} MB"),
+        );
+    }
+    if stderr.contains("has overflowed its stack") {
+        return "overflowed its stack".to_owned();
+    }
+
+    #[cfg(unix)]
+    if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(&status) {
+        return format!("crashed with signal {signal}");
+    }
+    format!("crashed, {status}")
+}
+
+#[test]
+fn child_runs_round_trip() {
+    use crate::types::{BenchTimes, Info};
+
+    let result = ProblemResult::from((
+        ProblemResult::lines("a\nb"),
+        ProblemResult::Other(Box::new(vec![1, 2])),
+    ));
+    let output = Output::from(&result);
+    let json = serde_json::to_string(&output).unwrap();
+    let back = ProblemResult::from(serde_json::from_str::<Output>(&json).unwrap());
+    assert_eq!(back.to_string(), result.to_string());
+    assert_eq!(
+        Output::from(&ProblemResult::Big(i128::MAX)),
+        Output::Big(i128::MAX)
+    );
+
+    let solution = Box::leak(Box::new(crate::types::Solution {
+        part1: |_| Ok(ProblemResult::Number(1)),
+        part2: None,
+        parse: None,
+        info: Info {
+            name: "a",
+            day: 1,
+            year: 2022,
+            bench: BenchTimes::Once,
+        },
+        params: &[],
+        examples: &[],
+        other: &[],
+    }));
+    let entry = crate::select::entries(solution).remove(0);
+
+    let stats = Stats::once(Duration::from_micros(3));
+    let run = BenchRun::new(entry.info, "part1".to_owned(), 7.into(), &stats, stats.mean)
+        .with_phases(Some(Duration::from_micros(1)), Duration::from_micros(2));
+    let json = serde_json::to_string(&ChildRun::from(&run)).unwrap();
+    let back = serde_json::from_str::<ChildRun>(&json)
+        .unwrap()
+        .into_run(&entry);
+    assert_eq!(back.output.as_ref().unwrap(), &ProblemResult::Number(7));
+    assert_eq!(back.stats(), run.stats());
+    assert_eq!(back.parse_time, run.parse_time);
+
+    let failed = BenchRun::failed(entry.info, "part1".to_owned(), anyhow::anyhow!("bad input"));
+    let json = serde_json::to_string(&ChildRun::from(&failed)).unwrap();
+    let back = serde_json::from_str::<ChildRun>(&json)
+        .unwrap()
+        .into_run(&entry);
+    assert_eq!(back.output.unwrap_err().to_string(), "bad input");
+}
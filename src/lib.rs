@@ -12,31 +12,162 @@ use core::time;
 use std::fmt::{Debug, Display};
 use std::time::Instant;
 
-use rayon::iter::ParallelBridge;
-use rayon::prelude::*;
-
+use anyhow::Context;
+
+pub use allocations::{Allocations, CountingAlloc};
+pub use answers::{Answers, Verdict};
+pub use aoc_client::{AocClient, Throttle};
+pub use bench_history::{BenchHistory, BenchRecord, Change, Comparison, Machine, Revision};
+pub use examples::{check as check_examples, Example};
+pub use get_input::{CacheEntry, InputCache};
+pub use input_source::{
+    sources_from_spec, suspicious_input, FetchError, InputSource, LocalDir, WebSource,
+};
+pub use output::Format;
+pub use stats::{Running, Stats};
+pub use submit::{History, Outcome, Submission};
 pub use types::*;
 
+mod allocations;
+mod answers;
+mod aoc_client;
+mod bench_history;
+mod cache_command;
+mod calendar;
+mod cli;
+mod examples;
 mod get_input;
+mod input_source;
+mod isolate;
+mod new_command;
+pub mod ocr;
+mod output;
+mod schedule;
+mod select;
+mod stats;
+mod submit;
+mod watch;
 
 pub mod types {
     use core::time;
+    use std::any::{type_name, Any};
     use std::fmt::{self, Debug, Display};
+    use std::ops::Deref;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use clap::Parser;
+    use cli_table::{format::Justify, Color, Table};
+
+    use anyhow::Context;
+
+    use crate::allocations::Allocations;
+    use crate::answers::{Answers, Verdict};
+    use crate::aoc_client::AocClient;
+    use crate::bench_history::{print_comparisons, BenchHistory, Machine, Revision};
+    use crate::cli::{Cli, Command, RunArgs, Selection, TestArgs};
+    use crate::examples::{self, Example};
+    use crate::new_command;
+    use crate::output::{print_runs, Format};
+    use crate::select::Entry;
+    use crate::stats::Stats;
+    use crate::submit::{History, Outcome};
+    use crate::{
+        catch_panic, get_input::InputCache, parse_input, run_solution, time_bench_with, FetchError,
+    };
 
-    use cli_table::{format::Justify, Color, Table, WithTitle};
-    use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+    pub type SolutionFn = fn(&Input) -> anyhow::Result<ProblemResult>;
 
-    use anyhow::anyhow;
-    use rayon::iter::IntoParallelRefIterator;
+    /// the intermediate value a `ParseFn` produces, see `Input::parsed`
+    pub type Parsed = Box<dyn Any + Send + Sync>;
+    /// parses the input once, so all parts of a day share the result
+    pub type ParseFn = fn(&str) -> anyhow::Result<Parsed>;
 
-    use crate::{get_input::InputCache, time_bench_solution};
+    /// the input of a part, derefs to the raw text so plain `&str` solutions keep working
+    #[derive(Clone, Copy)]
+    pub struct Input<'a> {
+        text: &'a str,
+        parsed: Option<&'a (dyn Any + Send + Sync)>,
+        params: &'a [(&'a str, &'a str)],
+    }
 
-    pub type SolutionFn = fn(&str) -> ProblemResult;
+    impl<'a> Input<'a> {
+        #[must_use]
+        pub const fn new(text: &'a str) -> Self {
+            Self {
+                text,
+                parsed: None,
+                params: &[],
+            }
+        }
+
+        #[must_use]
+        pub const fn with_parsed(text: &'a str, parsed: &'a (dyn Any + Send + Sync)) -> Self {
+            Self {
+                text,
+                parsed: Some(parsed),
+                params: &[],
+            }
+        }
+
+        #[must_use]
+        pub const fn with_params(mut self, params: &'a [(&'a str, &'a str)]) -> Self {
+            self.params = params;
+            self
+        }
+
+        /// the puzzle parameter `name`, set on the command line or by the running example,
+        /// else the default the `Solution` declares
+        pub fn param<T>(&self, name: &str) -> anyhow::Result<T>
+        where
+            T: FromStr,
+            T::Err: Display,
+        {
+            let value = self
+                .params
+                .iter()
+                .find(|(it, _)| *it == name)
+                .map(|(_, value)| *value)
+                .with_context(|| format!("the solution declares no parameter {name:?}"))?;
+
+            value
+                .parse()
+                .map_err(|err| anyhow::anyhow!("invalid {name} {value:?}: {err}"))
+        }
+
+        #[must_use]
+        pub const fn text(&self) -> &'a str {
+            self.text
+        }
+
+        /// the value the `parse` of the `Solution` produced
+        ///
+        /// # Panics
+        /// if the solution has no parser, or it produced another type
+        #[must_use]
+        pub fn parsed<T: Any>(&self) -> &'a T {
+            self.parsed
+                .expect("the solution has no parser")
+                .downcast_ref()
+                .unwrap_or_else(|| panic!("the parser did not produce a {}", type_name::<T>()))
+        }
+    }
+
+    impl Deref for Input<'_> {
+        type Target = str;
+
+        fn deref(&self) -> &str {
+            self.text
+        }
+    }
 
     use rayon::prelude::*;
 
     pub struct AocRuntime {
         pub input_cache: InputCache,
+        answers: Answers,
+        /// days that were skipped because their input could not be loaded
+        skipped: Vec<(u16, u8)>,
     }
 
     impl AocRuntime {
@@ -44,75 +175,501 @@ pub mod types {
         ///
         /// # Errors
         /// - `InputCache` fails to build
-        ///     => dotenv fails to load
+        ///   => the input sources are misconfigured
+        /// - `answers.json` is invalid
         pub fn new() -> anyhow::Result<Self> {
             Ok(Self {
                 input_cache: InputCache::new()?,
+                answers: Answers::open()?,
+                skipped: Vec::new(),
             })
         }
 
+        #[must_use]
+        pub const fn answers(&self) -> &Answers {
+            &self.answers
+        }
+
+        /// gets the input for a day, or reports why there is none and skips the day
+        pub fn input_for(&mut self, date: &dyn DateProvider) -> Option<String> {
+            let (year, day) = date.get_datetuple();
+            if self.skipped.contains(&(year, day)) {
+                return None;
+            }
+
+            let err = match self.input_cache.get(date) {
+                Ok(input) => return Some(input),
+                Err(err) => err,
+            };
+
+            match err.chain().find_map(|it| it.downcast_ref::<FetchError>()) {
+                Some(FetchError::Unauthenticated) => eprintln!(
+                    "skipping {year} day {day}: {}, refresh it from the session cookie on adventofcode.com",
+                    FetchError::Unauthenticated
+                ),
+                Some(fetch_err) => eprintln!("skipping {year} day {day}: {fetch_err}"),
+                None => eprintln!("skipping {year} day {day}: {err:#}"),
+            }
+            self.skipped.push((year, day));
+
+            None
+        }
+
+        /// fails if days had to be skipped, so missing inputs are not mistaken for success
+        fn check_skipped(&self) -> anyhow::Result<()> {
+            match self.skipped.as_slice() {
+                [] => Ok(()),
+                skipped => anyhow::bail!(
+                    "{} day(s) were skipped because their input is unavailable: {}",
+                    skipped.len(),
+                    skipped
+                        .iter()
+                        .map(|(year, day)| format!("{year} day {day}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }
+        }
+
+        /// parses the command line and runs the subcommand
         pub fn run(&mut self, days: &'static [Solution]) -> anyhow::Result<()> {
-            if let Some(usr_query) = std::env::args().nth(1) {
-                let matcher = SkimMatcherV2::default();
-
-                let matched_benches = days
-                    .par_iter()
-                    .flat_map(get_names)
-                    // if the name is matched, return only the name
-                    .filter_map(|name| matcher.fuzzy_match(&name.0, &usr_query).map(|_| name))
-                    .collect::<Vec<_>>();
+            let cli = Cli::parse();
 
-                if matched_benches.is_empty() {
-                    anyhow::bail!("No Matches found!");
+            let run_args = match &cli.command {
+                None => Some(&cli.bench),
+                Some(Command::Run(args) | Command::Bench(args) | Command::Child { args, .. }) => {
+                    Some(args)
                 }
+                _ => None,
+            };
+            if let Some(args) = run_args.filter(|it| it.dry_run) {
+                args.select.print(&args.select.select(days));
+                return Ok(());
+            }
+            // isolated entries size the pool of their own process
+            if let Some(threads) = run_args
+                .filter(|it| !it.isolated())
+                .and_then(|it| it.threads)
+            {
+                crate::schedule::set_threads(threads)?;
+            }
 
-                let result = matched_benches
-                    .into_iter()
-                    .map(|it| (self.input_cache.get(it.2).unwrap(), it))
-                    .par_bridge()
-                    .map(|(inp, (label, f, info))| time_bench_solution(&inp, info, label, &f))
+            match cli.command {
+                None => self.bench(days, &cli.bench),
+                Some(Command::Bench(args)) => self.bench(days, &args),
+                Some(Command::Run(args)) => self.run_once(days, &args),
+                Some(Command::List(select)) => {
+                    select.print(&select.select(days));
+                    Ok(())
+                }
+                Some(Command::Fetch(select)) => self.fetch(days, &select),
+                Some(Command::Accept(select)) => self.accept(days, &select),
+                Some(Command::Submit(select)) => self.submit(days, &select),
+                Some(Command::Test(args)) => self.test(days, &args),
+                Some(Command::Watch(select)) => crate::watch::run(days, &select, &self.input_cache),
+                Some(Command::Cache { action }) => {
+                    crate::cache_command::run(&mut self.input_cache, &action)
+                }
+                Some(Command::New { year, day }) => self.new_day(days, (year, day)),
+                Some(Command::Calendar { year }) => {
+                    crate::calendar::print(days, &year, &self.answers)
+                }
+                Some(Command::Child { bench, args }) => self.child(days, &args, bench),
+            }
+        }
+
+        fn bench(&mut self, days: &'static [Solution], args: &RunArgs) -> anyhow::Result<()> {
+            let inputs = self.inputs_for(days, args)?;
+            let mut result = if args.isolated() {
+                crate::isolate::run(&inputs, args, true)
+            } else {
+                Self::bench_entries(inputs, args)?
+            };
+
+            self.report(&mut result, args)?;
+            let regressions = Self::record(&result, args)?;
+            self.check(&result)?;
+
+            if regressions > 0 {
+                anyhow::bail!(
+                    "{regressions} benchmark(s) are more than {}% slower than the baseline",
+                    args.threshold
+                );
+            }
+            Ok(())
+        }
+
+        /// benchmarks the entries one after another, or concurrently with `--parallel`.
+        /// errors and panics fail only their own entry
+        fn bench_entries(
+            inputs: Vec<(String, Entry)>,
+            args: &RunArgs,
+        ) -> anyhow::Result<Vec<BenchRun>> {
+            let params = args.params();
+            let bench_entry = |(inp, entry): (String, Entry)| {
+                // days that are too slow to repeat keep their single run, or none
+                let bench = match &entry.info.bench {
+                    BenchTimes::Default | BenchTimes::Many(_) if args.adaptive => {
+                        &BenchTimes::Adaptive
+                    }
+                    bench => bench,
+                };
+                catch_panic(|| {
+                    time_bench_with(
+                        &inp,
+                        &entry.params(&params),
+                        entry.info,
+                        entry.label.to_owned(),
+                        entry.parse,
+                        entry.f,
+                        bench,
+                    )
+                })
+                .unwrap_or_else(|err| BenchRun::failed(entry.info, entry.label.to_owned(), err))
+            };
+
+            if args.parallel {
+                return Ok(inputs.into_par_iter().map(bench_entry).collect());
+            }
+            if let Some(core) = args.pin {
+                crate::schedule::pin_current_thread(core)?;
+            }
+            Ok(inputs.into_iter().map(bench_entry).collect())
+        }
+
+        /// adds the runs to the benchmark history and compares them with the baseline,
+        /// returns the number of regressions
+        fn record(runs: &[BenchRun], args: &RunArgs) -> anyhow::Result<usize> {
+            // timings on other inputs or parameters can not be compared
+            if args.input.is_some() || args.stdin || !args.params.is_empty() {
+                return Ok(0);
+            }
+
+            let mut history = BenchHistory::open()?;
+            let machine = Machine::current();
+
+            let comparisons = args.compare.as_ref().map(|spec| {
+                history
+                    .baseline(spec, &machine)
+                    .map(|it| it.compare(runs, args.threshold))
+            });
+
+            // recorded even without a baseline, so the next run has one
+            if !args.no_record {
+                let records =
+                    BenchHistory::records_for(runs, Revision::current().as_ref(), &machine);
+                history.append(records)?;
+            }
+
+            match comparisons {
+                // machine readable results on stdout stay parseable
+                Some(comparisons) => print_comparisons(&comparisons?, args.format != Format::Table),
+                None => Ok(0),
+            }
+        }
+
+        /// runs every selected entry once, without benchmarking
+        fn run_once(&mut self, days: &'static [Solution], args: &RunArgs) -> anyhow::Result<()> {
+            let inputs = self.inputs_for(days, args)?;
+            let mut result = if args.isolated() {
+                crate::isolate::run(&inputs, args, false)
+            } else {
+                Self::run_entries(&inputs, &args.params())
+            };
+
+            self.report(&mut result, args)?;
+            self.check(&result)
+        }
+
+        /// the child process of `--isolate`, runs the entries in this process
+        /// and prints them for the parent
+        fn child(
+            &mut self,
+            days: &'static [Solution],
+            args: &RunArgs,
+            bench: bool,
+        ) -> anyhow::Result<()> {
+            let inputs = self.inputs_for(days, args)?;
+            let runs = if bench {
+                Self::bench_entries(inputs, args)?
+            } else {
+                Self::run_entries(&inputs, &args.params())
+            };
+            crate::isolate::print_runs(&runs)
+        }
+
+        /// runs the entries once with the parameters, the parts of a day share one parse of its input.
+        /// errors and panics fail only their own entry
+        fn run_entries(inputs: &[(String, Entry)], params: &[(&str, &str)]) -> Vec<BenchRun> {
+            let mut runs = Vec::with_capacity(inputs.len());
+            let mut parsed = None;
+
+            for (inp, entry) in inputs {
+                let date = entry.info.get_datetuple();
+                if parsed.as_ref().is_none_or(|(it, _)| *it != date) {
+                    let result = catch_panic(|| parse_input(inp, entry.parse));
+                    parsed = Some((date, result.map_err(|err| format!("{err:#}"))));
+                }
+
+                let run = match parsed.as_ref().map(|(_, it)| it) {
+                    Some(Ok(parsed)) => catch_panic(|| {
+                        let label = entry.label.to_owned();
+                        let params = entry.params(params);
+                        run_solution(inp, &params, parsed, entry.info, label, entry.f)
+                    }),
+                    Some(Err(err)) => Err(anyhow::anyhow!("{err}")),
+                    None => unreachable!("parsed above"),
+                };
+                runs.push(run.unwrap_or_else(|err| {
+                    BenchRun::failed(entry.info, entry.label.to_owned(), err)
+                }));
+            }
+            runs
+        }
+
+        /// checks the results against the expected answers and prints them
+        fn report(&self, runs: &mut [BenchRun], args: &RunArgs) -> anyhow::Result<()> {
+            // the expected answers are only known for the cached inputs and the default parameters
+            if args.input.is_none() && !args.stdin && args.params.is_empty() {
+                for run in runs.iter_mut() {
+                    run.verdict = self.answers.verdict(run);
+                }
+            }
+
+            print_runs(runs, args.format)
+        }
+
+        /// fails if days were skipped, or entries failed or are wrong
+        fn check(&self, runs: &[BenchRun]) -> anyhow::Result<()> {
+            self.check_skipped()?;
+
+            let failed = runs
+                .iter()
+                .filter(|it| it.output.is_err())
+                .map(|it| format!("{} day {}: {}", it.year, it.day, it.label))
+                .collect::<Vec<_>>();
+            if !failed.is_empty() {
+                anyhow::bail!("{} entries failed: {}", failed.len(), failed.join(", "));
+            }
+
+            let wrong = runs
+                .iter()
+                .filter(|it| matches!(it.verdict, Verdict::Wrong(_)))
+                .map(|it| format!("{} day {}: {}", it.year, it.day, it.label))
+                .collect::<Vec<_>>();
+            if !wrong.is_empty() {
+                anyhow::bail!(
+                    "{} result(s) differ from the expected answers: {}",
+                    wrong.len(),
+                    wrong.join(", ")
+                );
+            }
+            Ok(())
+        }
+
+        /// runs the selected entries once and stores their results as the expected answers
+        fn accept(&mut self, days: &'static [Solution], select: &Selection) -> anyhow::Result<()> {
+            let args = RunArgs {
+                select: select.clone(),
+                ..Default::default()
+            };
+
+            let inputs = self.inputs_for(days, &args)?;
+            let runs = Self::run_entries(&inputs, &[]);
+            for ((_, entry), run) in inputs.iter().zip(&runs) {
+                let output = match &run.output {
+                    Ok(output) => output,
+                    Err(err) => {
+                        eprintln!("{}: failed, {err:#}", entry.name);
+                        continue;
+                    }
+                };
+
+                match self.answers.accept(run.year, run.day, &run.label, output) {
+                    Some(old) if old != output.to_string() => {
+                        eprintln!("{}: {output} (was {old})", entry.name);
+                    }
+                    _ => eprintln!("{}: {output}", entry.name),
+                }
+            }
+
+            self.answers.save()?;
+            eprintln!("saved to {}", self.answers.path().display());
+            self.check(&runs)
+        }
+
+        /// runs the selected entries on the examples of their days and checks the answers
+        fn test(&self, days: &'static [Solution], args: &TestArgs) -> anyhow::Result<()> {
+            let runs = examples::run(&args.select.select(days));
+            if runs.is_empty() {
+                anyhow::bail!("none of the selected solutions have examples with answers");
+            }
+
+            print_runs(&runs, args.format)?;
+            self.check(&runs)
+        }
+
+        /// runs the selected part once and submits its result,
+        /// a correct answer is stored as the expected answer
+        fn submit(&mut self, days: &'static [Solution], select: &Selection) -> anyhow::Result<()> {
+            let selected = select.select(days);
+            let [entry] = selected.as_slice() else {
+                let names = selected
+                    .iter()
+                    .map(|it| it.name.as_str())
                     .collect::<Vec<_>>();
+                anyhow::bail!("submit needs exactly one part to be selected, got {names:?}");
+            };
+
+            let level = match entry.label {
+                "part1" => 1,
+                "part2" => 2,
+                label => anyhow::bail!("only part1 and part2 can be submitted, not {label:?}"),
+            };
+
+            let Some(inp) = self.input_for(entry.info) else {
+                return self.check_skipped();
+            };
+            let run = run_solution(
+                &inp,
+                &entry.params(&[]),
+                &parse_input(&inp, entry.parse)?,
+                entry.info,
+                entry.label.to_owned(),
+                entry.f,
+            )?;
+            let output = run.output?;
+            let answer = output.to_string();
+
+            let mut client = AocClient::from_env()?;
+            let mut history = History::open()?;
+
+            eprintln!("submitting {answer} for {}", entry.name);
+            let outcome = crate::submit::submit(
+                &mut client,
+                &mut history,
+                (run.year, run.day, level),
+                &answer,
+            )?;
+            println!("{outcome}");
+
+            if outcome == Outcome::Correct {
+                self.answers.accept(run.year, run.day, &run.label, &output);
+                self.answers.save()?;
+            }
+            Ok(())
+        }
 
-                return cli_table::print_stdout(result.with_title())
-                    .map_err(|_| anyhow!("Failed to print table"));
+        /// creates `src/yr{year}/day{day}.rs` from the template with an empty example input,
+        /// and caches the input of the day if it is available already
+        fn new_day(
+            &mut self,
+            days: &'static [Solution],
+            (year, day): (u16, u8),
+        ) -> anyhow::Result<()> {
+            if let Some(solution) = days.iter().find(|it| it.get_datetuple() == (year, day)) {
+                anyhow::bail!("{year} day {day} is solved already: {}", solution.info.name);
             }
 
-            let runs = crate::bench_solutions(days, self);
-            cli_table::print_stdout(runs.with_title()).map_err(|_| anyhow!("Failed to print table"))
+            let placeholder = || format!("Day {day}");
+            let title = if self.input_cache.is_offline() {
+                placeholder()
+            } else {
+                AocClient::from_env()
+                    .and_then(|mut client| new_command::fetch_title(&mut client, year, day))
+                    .unwrap_or_else(|err| {
+                        eprintln!(
+                            "using a placeholder title, the puzzle page is unavailable: {err:#}"
+                        );
+                        placeholder()
+                    })
+            };
+
+            let (source, example) =
+                new_command::create(Path::new("src"), Path::new("inputs"), (year, day), &title)?;
+            eprintln!("created {} for {year} day {day}: {title}", source.display());
+            eprintln!("paste the example of the puzzle into {}", example.display());
+
+            if self.input_for(&(year, day)).is_some() {
+                eprintln!("{year} day {day} is cached");
+            }
+            Ok(())
         }
-    }
 
-    /// formats the names for each function available for the Solution, returns a Vec of (name, fn)
-    // avoids type golfing
-    #[allow(clippy::trivially_copy_pass_by_ref)]
-    fn get_names(inp: &'static Solution) -> Vec<(String, crate::SolutionFn, &'static crate::Info)> {
-        let x = inp.part2.map_or_else(
-            || vec![("part1", inp.part1)],
-            |part2| vec![("part2", part2), ("part1", inp.part1)],
-        );
-
-        let others = inp.other.iter().map(|(a, b, _)| (*a, *b));
-
-        // x.into_iter()
-        //     .chain(others)
-
-        others
-            .into_iter()
-            .chain(x)
-            .map(|s| {
-                (
-                    format!("{} day{:0>2}: {}", inp.info.year, inp.info.day, s.0),
-                    s.1,
-                    &inp.info,
+        /// downloads the inputs of the selected days, so later runs work offline
+        fn fetch(&mut self, days: &'static [Solution], select: &Selection) -> anyhow::Result<()> {
+            let mut dates = select
+                .select(days)
+                .iter()
+                .map(|it| it.info.get_datetuple())
+                .collect::<Vec<_>>();
+            dates.dedup();
+
+            for date in dates {
+                if self.input_for(&date).is_some() {
+                    eprintln!("{} day {} is cached", date.0, date.1);
+                }
+            }
+            self.check_skipped()
+        }
+
+        /// selects the entries and pairs them with their input,
+        /// `--input` and `--stdin` replace the input of a single selected day
+        fn inputs_for(
+            &mut self,
+            days: &'static [Solution],
+            args: &RunArgs,
+        ) -> anyhow::Result<Vec<(String, Entry)>> {
+            let selected = args.select.select(days);
+            if selected.is_empty() {
+                anyhow::bail!("No Matches found!");
+            }
+            for (name, _) in &args.params {
+                if !selected
+                    .iter()
+                    .any(|it| it.params.iter().any(|(it, _)| it == name))
+                {
+                    anyhow::bail!("none of the selected solutions have a parameter {name:?}");
+                }
+            }
+
+            let input = if let Some(path) = &args.input {
+                Some(
+                    std::fs::read_to_string(path)
+                        .with_context(|| format!("failed to read {}", path.display()))?,
                 )
-            })
-            .collect::<Vec<_>>()
+            } else if args.stdin {
+                Some(std::io::read_to_string(std::io::stdin()).context("failed to read stdin")?)
+            } else {
+                None
+            };
+
+            let Some(input) = input else {
+                return Ok(selected
+                    .into_iter()
+                    .filter_map(|it| Some((self.input_for(it.info)?, it)))
+                    .collect());
+            };
+
+            let first = selected[0].info.get_datetuple();
+            if selected.iter().any(|it| it.info.get_datetuple() != first) {
+                anyhow::bail!("--input and --stdin need a selection of a single day");
+            }
+
+            Ok(selected.into_iter().map(|it| (input.clone(), it)).collect())
+        }
     }
 
     pub struct Solution {
-        pub part1: fn(&str) -> ProblemResult,
-        pub part2: Option<fn(&str) -> ProblemResult>,
+        pub part1: SolutionFn,
+        pub part2: Option<SolutionFn>,
+        /// runs before the parts, which get its result from `Input::parsed`
+        pub parse: Option<ParseFn>,
         pub info: Info,
+        /// checked by the `test` command
+        pub examples: &'static [Example],
+        /// the puzzle parameters with their defaults, see `Input::param`
+        pub params: &'static [(&'static str, &'static str)],
         pub other: &'static [(&'static str, SolutionFn, Run)],
     }
 
@@ -124,9 +681,26 @@ pub mod types {
     #[derive(Debug)]
     pub enum ProblemResult {
         Number(i64),
+        /// integers that do not fit in an `i64`
+        Big(i128),
+        Text(String),
+        /// multi-line output like a picture or a grid, one entry per line
+        Lines(Vec<String>),
+        /// two answers from one run, like both parts of a day
+        Pair(Box<(Self, Self)>),
+        /// a run without an answer
+        Unit,
         Other(Box<dyn Debug + Send + Sync>),
     }
 
+    impl ProblemResult {
+        /// splits multi-line text, like a grid, into `Lines`
+        #[must_use]
+        pub fn lines(text: &str) -> Self {
+            Self::Lines(text.lines().map(str::to_owned).collect())
+        }
+    }
+
     #[derive(Debug)]
     pub struct Info {
         pub name: &'static str,
@@ -141,6 +715,8 @@ pub mod types {
         Default,
         Once,
         Many(usize),
+        /// samples until the mean is stable or the time budget is used up
+        Adaptive,
     }
 
     #[derive(Debug, PartialEq, Eq, Clone)]
@@ -165,24 +741,181 @@ pub mod types {
 
         #[table(display_fn = "display_duration", title = "avg", color = "Color::Cyan")]
         pub avg_time: time::Duration,
+        /// the mean time of the parser of the day, `None` without one
+        #[table(display_fn = "display_parse", title = "parse")]
+        pub parse_time: Option<time::Duration>,
+        /// the mean time of the part itself
+        #[table(display_fn = "display_duration", title = "solve")]
+        pub solve_time: time::Duration,
+        #[table(display_fn = "display_duration", title = "median")]
+        pub median: time::Duration,
+        #[table(display_fn = "display_stddev", title = "stddev")]
+        pub stddev: time::Duration,
+        #[table(display_fn = "display_duration", title = "min")]
+        pub min: time::Duration,
+        #[table(display_fn = "display_duration", title = "max")]
+        pub max: time::Duration,
+        #[table(display_fn = "display_duration", title = "p95")]
+        pub p95: time::Duration,
         #[table(display_fn = "display_duration", title = "elapsed", skip)]
         pub elapsed: time::Duration,
-        #[table(title = "Benchmarked", display_fn = "_display_times", skip)]
+        #[table(
+            title = "runs",
+            display_fn = "display_times",
+            justify = "Justify::Right"
+        )]
         pub times: usize,
-
-        #[table(title = "result", color = "Color::Green")]
-        pub output: ProblemResult,
+        #[table(title = "outliers", justify = "Justify::Right")]
+        pub outliers: usize,
+        /// allocations of the last run, with the `count-allocations` feature
+        #[table(
+            title = "allocs",
+            display_fn = "display_count",
+            justify = "Justify::Right"
+        )]
+        pub alloc_count: Option<usize>,
+        #[table(title = "allocated", display_fn = "display_bytes")]
+        pub alloc_bytes: Option<usize>,
+        #[table(title = "peak", display_fn = "display_bytes")]
+        pub peak_bytes: Option<usize>,
+
+        /// the error or panic message of a failed entry
+        #[table(
+            title = "result",
+            display_fn = "display_output",
+            color = "Color::Green"
+        )]
+        pub output: anyhow::Result<ProblemResult>,
+        #[table(title = "check")]
+        pub verdict: Verdict,
     }
 
     fn display_duration(inp: &time::Duration) -> impl Display {
         format!("{inp:?}")
     }
 
+    #[allow(clippy::ref_option)]
+    fn display_parse(inp: &Option<time::Duration>) -> impl Display {
+        inp.map_or_else(|| "-".to_owned(), |it| format!("{it:?}"))
+    }
+
+    fn display_output(inp: &anyhow::Result<ProblemResult>) -> impl Display {
+        match inp {
+            Ok(output) => output.to_string(),
+            Err(err) => format!("failed: {err:#}"),
+        }
+    }
+
+    fn display_stddev(inp: &time::Duration) -> impl Display {
+        format!("± {inp:?}")
+    }
+
     #[allow(clippy::trivially_copy_pass_by_ref)]
-    fn _display_times(inp: &usize) -> impl Display {
+    fn display_times(inp: &usize) -> impl Display {
         format!("{inp}x")
     }
 
+    #[allow(clippy::ref_option)]
+    fn display_count(inp: &Option<usize>) -> impl Display {
+        inp.map_or_else(|| "-".to_owned(), |it| it.to_string())
+    }
+
+    #[allow(clippy::ref_option)]
+    fn display_bytes(inp: &Option<usize>) -> impl Display {
+        inp.map_or_else(|| "-".to_owned(), crate::allocations::format_bytes)
+    }
+
+    impl BenchRun {
+        /// the stats the run was built from
+        #[must_use]
+        pub const fn stats(&self) -> Stats {
+            Stats {
+                samples: self.times,
+                mean: self.avg_time,
+                median: self.median,
+                min: self.min,
+                max: self.max,
+                stddev: self.stddev,
+                p95: self.p95,
+                outliers: self.outliers,
+            }
+        }
+
+        #[must_use]
+        pub const fn new(
+            info: &Info,
+            label: String,
+            output: ProblemResult,
+            stats: &Stats,
+            elapsed: time::Duration,
+        ) -> Self {
+            Self {
+                year: info.year,
+                day: info.day,
+                name: info.name,
+                label,
+                avg_time: stats.mean,
+                parse_time: None,
+                solve_time: stats.mean,
+                median: stats.median,
+                stddev: stats.stddev,
+                min: stats.min,
+                max: stats.max,
+                p95: stats.p95,
+                elapsed,
+                times: stats.samples,
+                outliers: stats.outliers,
+                alloc_count: None,
+                alloc_bytes: None,
+                peak_bytes: None,
+                output: Ok(output),
+                verdict: Verdict::Unknown,
+            }
+        }
+
+        /// a run of an entry that returned an error or panicked
+        #[must_use]
+        pub fn failed(info: &Info, label: String, err: anyhow::Error) -> Self {
+            let stats = Stats::once(time::Duration::ZERO);
+            Self {
+                times: 0,
+                output: Err(err),
+                ..Self::new(info, label, ProblemResult::Number(0), &stats, stats.mean)
+            }
+        }
+
+        /// splits the time into parsing and solving
+        #[must_use]
+        pub const fn with_phases(
+            mut self,
+            parse_time: Option<time::Duration>,
+            solve_time: time::Duration,
+        ) -> Self {
+            self.parse_time = parse_time;
+            self.solve_time = solve_time;
+            self
+        }
+
+        /// what the run allocated, if it was counted
+        #[must_use]
+        pub const fn allocations(&self) -> Option<Allocations> {
+            match (self.alloc_count, self.alloc_bytes, self.peak_bytes) {
+                (Some(count), Some(bytes), Some(peak)) => Some(Allocations { count, bytes, peak }),
+                _ => None,
+            }
+        }
+
+        #[must_use]
+        pub const fn with_allocations(mut self, allocations: Option<Allocations>) -> Self {
+            if let Some(Allocations { count, bytes, peak }) = allocations {
+                self.alloc_count = Some(count);
+                self.alloc_bytes = Some(bytes);
+                self.peak_bytes = Some(peak);
+            }
+            self
+        }
+    }
+
     pub trait DateProvider {
         fn get_datetuple(&self) -> (u16, u8);
         fn day(&self) -> u8;
@@ -217,26 +950,95 @@ pub mod types {
         }
     }
 
+    impl DateProvider for (u16, u8) {
+        fn get_datetuple(&self) -> (u16, u8) {
+            *self
+        }
+
+        fn day(&self) -> u8 {
+            self.1
+        }
+
+        fn year(&self) -> u16 {
+            self.0
+        }
+    }
+
     impl Display for ProblemResult {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             match self {
                 Self::Number(n) => write!(f, "{n}"),
+                Self::Big(n) => write!(f, "{n}"),
+                Self::Text(text) => write!(f, "{text}"),
+                Self::Lines(lines) => write!(f, "{}", lines.join("\n")),
+                Self::Pair(pair) => write!(f, "({}, {})", pair.0, pair.1),
+                Self::Unit => write!(f, "-"),
                 Self::Other(any) => write!(f, "{any:?}"),
             }
         }
     }
 
+    /// `Other` compares the `Debug` output
+    impl PartialEq for ProblemResult {
+        fn eq(&self, other: &Self) -> bool {
+            match (self, other) {
+                (Self::Number(a), Self::Number(b)) => a == b,
+                (Self::Big(a), Self::Big(b)) => a == b,
+                (Self::Number(a), Self::Big(b)) | (Self::Big(b), Self::Number(a)) => {
+                    i128::from(*a) == *b
+                }
+                (Self::Text(a), Self::Text(b)) => a == b,
+                (Self::Lines(a), Self::Lines(b)) => a == b,
+                (Self::Pair(a), Self::Pair(b)) => a == b,
+                (Self::Unit, Self::Unit) => true,
+                (Self::Other(a), Self::Other(b)) => format!("{a:?}") == format!("{b:?}"),
+                _ => false,
+            }
+        }
+    }
+
+    impl From<String> for ProblemResult {
+        fn from(value: String) -> Self {
+            Self::Text(value)
+        }
+    }
+
+    impl From<&str> for ProblemResult {
+        fn from(value: &str) -> Self {
+            Self::Text(value.to_owned())
+        }
+    }
+
+    impl From<()> for ProblemResult {
+        fn from((): ()) -> Self {
+            Self::Unit
+        }
+    }
+
+    impl<A: Into<Self>, B: Into<Self>> From<(A, B)> for ProblemResult {
+        fn from((a, b): (A, B)) -> Self {
+            Self::Pair(Box::new((a.into(), b.into())))
+        }
+    }
+
+    /// numbers that do not fit become `Big`, or `Text` beyond `i128`
     macro_rules! impl_from_problem_num {
-    ( $($t:ty),* ) => {
-        $(
-			impl From<$t> for ProblemResult {
-				fn from(value: $t) -> Self {
-					ProblemResult::Number(value.try_into().unwrap())
-				}
-			}
-			)*
-    	};
-	}
+        ( $($t:ty),* ) => {
+            $(
+                impl From<$t> for ProblemResult {
+                    fn from(value: $t) -> Self {
+                        i64::try_from(value).map_or_else(
+                            |_| {
+                                i128::try_from(value)
+                                    .map_or_else(|_| Self::Text(value.to_string()), Self::Big)
+                            },
+                            Self::Number,
+                        )
+                    }
+                }
+            )*
+        };
+    }
 
     impl_from_problem_num! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
 }
@@ -248,13 +1050,13 @@ pub fn time_dbg<R: Debug>(label: impl Display, f: impl Fn() -> R) -> R {
     result
 }
 
+/// times `f` `TIMES` times, one run after another so the runs do not interfere
 pub fn time_bench<const TIMES: usize, R>(label: impl Display, f: impl Fn() -> R + Send + Sync) -> R
 where
     R: Send + Sync + Debug,
 {
     let start = Instant::now();
     let times = (0..TIMES)
-        .par_bridge()
         .map(|_| {
             let time = Instant::now();
             let _ = f();
@@ -270,41 +1072,49 @@ where
     );
 
     let result = f();
-    eprintln!("{label} resulted in {result:?}",);
+    eprintln!("{label} resulted in {result:?}");
     result
 }
 
+/// whether a solution before `index` has the same year and day as the one at `index`.
+/// asserted for every solution at compile time, see `build.rs`
+#[must_use]
+pub const fn solved_before(days: &[Solution], index: usize) -> bool {
+    let info = &days[index].info;
+    let mut i = 0;
+    while i < index {
+        if days[i].info.year == info.year && days[i].info.day == info.day {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
 pub fn bench_solutions(days: &'static [Solution], runtime: &mut AocRuntime) -> Vec<BenchRun> {
     let mut runs = Vec::new();
 
     for day in days.iter().rev() {
-        let input = &runtime.input_cache.get(day).unwrap();
-        // part1
-        runs.push(time_bench_solution(
-            input,
-            &day.info,
-            "part1".to_owned(),
-            &day.part1,
-        ));
-
-        // part2
-        if let Some(part2) = day.part2 {
-            runs.push(time_bench_solution(
-                input,
-                &day.info,
-                "part2".to_owned(),
-                &part2,
-            ));
-        }
-
-        // others
-        let iter = day.other.iter().filter_map(|(label, f, run)| {
-            crate::some_if! {
-                matches!(run, Run::Yes) => time_bench_solution(input, &day.info, (*label).to_string(), f)
-            }
-        });
+        let Some(input) = &runtime.input_for(day) else {
+            continue;
+        };
+        let parts = std::iter::once(("part1", day.part1))
+            .chain(day.part2.map(|part2| ("part2", part2)))
+            .chain(day.other.iter().filter_map(|(label, f, run)| {
+                crate::some_if! { matches!(run, Run::Yes) => (*label, *f) }
+            }));
+
+        runs.extend(parts.map(|(label, f)| {
+            catch_panic(|| {
+                let label = label.to_owned();
+                time_bench_solution(input, day.params, &day.info, label, day.parse, f)
+            })
+            .unwrap_or_else(|err| BenchRun::failed(&day.info, label.to_owned(), err))
+        }));
+    }
 
-        runs.extend(iter);
+    for run in &mut runs {
+        run.verdict = runtime.answers().verdict(run);
     }
     runs
 }
@@ -320,72 +1130,228 @@ macro_rules! some_if {
 	};
 }
 
+/// the longest the warmup runs before timing starts, even if runs are left
+const WARMUP: time::Duration = time::Duration::from_millis(100);
+/// adaptive benchmarks stop once the standard error of the mean is below this fraction of it
+const TARGET_RELATIVE_ERROR: f64 = 0.01;
+/// adaptive benchmarks stop after this long, even if the mean is not known precisely enough
+const ADAPTIVE_BUDGET: time::Duration = time::Duration::from_secs(3);
+const ADAPTIVE_MIN_SAMPLES: usize = 10;
+
 pub fn time_bench_solution(
     input: &str,
+    params: &[(&str, &str)],
     info: &Info,
     label: String,
-    f: &(dyn Fn(&str) -> ProblemResult + Send + Sync),
-) -> BenchRun {
-    let times = match info.bench {
-        BenchTimes::None => 0,
-        BenchTimes::Many(n) => n,
-        BenchTimes::Default => 100,
-        BenchTimes::Once => 1,
-    };
+    parse: Option<ParseFn>,
+    f: SolutionFn,
+) -> anyhow::Result<BenchRun> {
+    time_bench_with(input, params, info, label, parse, f, &info.bench)
+}
 
+/// benchmarks the solution `bench` times instead of the times set in its `Info`
+pub fn time_bench_with(
+    input: &str,
+    params: &[(&str, &str)],
+    info: &Info,
+    label: String,
+    parse: Option<ParseFn>,
+    f: SolutionFn,
+    bench: &BenchTimes,
+) -> anyhow::Result<BenchRun> {
     if label.contains("heavy") {
         eprintln!("Running heavy benchmark");
-        let start = Instant::now();
-        let output = f(input);
-        return BenchRun {
-            avg_time: start.elapsed(),
-            elapsed: start.elapsed(),
-            times: 1,
-            output,
-            day: info.day,
-            year: info.year,
-            name: info.name,
-            label,
+        let (run, allocations) = allocations::measure(|| {
+            run_solution(input, params, &parse_input(input, parse)?, info, label, f)
+        });
+        return Ok(run?.with_allocations(allocations));
+    }
+
+    let start = Instant::now();
+
+    let mut samples = if matches!(bench, BenchTimes::Adaptive) {
+        warmup(input, params, parse, f, ADAPTIVE_MIN_SAMPLES)?;
+        adaptive_samples(input, params, parse, f)?
+    } else {
+        let times = match bench {
+            BenchTimes::None => 0,
+            BenchTimes::Many(n) => *n,
+            BenchTimes::Default => 100,
+            BenchTimes::Once | BenchTimes::Adaptive => 1,
         };
+
+        // a single run is meant to be cold, or too slow to repeat
+        if times > 1 {
+            warmup(input, params, parse, f, (times / 10).max(1))?;
+        }
+        (0..times)
+            .map(|_| Ok(sample(input, params, parse, f)?.1))
+            .collect::<anyhow::Result<_>>()?
+    };
+
+    // the allocations of a warm run, parsing included
+    let (last, allocations) = allocations::measure(|| sample(input, params, parse, f));
+    let (output, last) = last?;
+    if samples.is_empty() {
+        samples.push(last);
     }
 
+    let totals = samples.iter().map(Sample::total).collect::<Vec<_>>();
+    let stats = Stats::from_samples(&totals).expect("there is at least one sample");
+    let (parse_time, solve_time) = Sample::means(&samples);
+
+    Ok(BenchRun::new(info, label, output, &stats, start.elapsed())
+        .with_phases(parse.map(|_| parse_time), solve_time)
+        .with_allocations(allocations))
+}
+
+/// the time of one run, split into parsing and solving
+#[derive(Clone, Copy)]
+struct Sample {
+    parse: time::Duration,
+    solve: time::Duration,
+}
+
+impl Sample {
+    fn total(&self) -> time::Duration {
+        self.parse + self.solve
+    }
+
+    /// the mean parse and solve time
+    fn means(samples: &[Self]) -> (time::Duration, time::Duration) {
+        let count = samples.len().max(1) as u32;
+        (
+            samples.iter().map(|it| it.parse).sum::<time::Duration>() / count,
+            samples.iter().map(|it| it.solve).sum::<time::Duration>() / count,
+        )
+    }
+}
+
+/// parses and solves from scratch, timing both
+fn sample(
+    input: &str,
+    params: &[(&str, &str)],
+    parse: Option<ParseFn>,
+    f: SolutionFn,
+) -> anyhow::Result<(ProblemResult, Sample)> {
+    let (parsed, parse) = parse_input(input, parse)?;
+
+    let time = Instant::now();
+    let output = core::hint::black_box(f(&input_of(input, parsed.as_ref()).with_params(params)))?;
+    let solve = time.elapsed();
+
+    Ok((output, Sample { parse, solve }))
+}
+
+/// runs the solution up to `runs` times, stopping early after `WARMUP`
+fn warmup(
+    input: &str,
+    params: &[(&str, &str)],
+    parse: Option<ParseFn>,
+    f: SolutionFn,
+    runs: usize,
+) -> anyhow::Result<()> {
     let start = Instant::now();
+    for _ in 0..runs {
+        sample(input, params, parse, f)?;
+        if start.elapsed() >= WARMUP {
+            break;
+        }
+    }
+    Ok(())
+}
 
-    let runs = (0..times)
-        .map(|_| {
-            let time = Instant::now();
-            core::hint::black_box(f(input));
-            time.elapsed()
-        })
-        .collect::<Vec<_>>();
+/// samples until the mean is known within `TARGET_RELATIVE_ERROR`, or `ADAPTIVE_BUDGET` is used up
+fn adaptive_samples(
+    input: &str,
+    params: &[(&str, &str)],
+    parse: Option<ParseFn>,
+    f: SolutionFn,
+) -> anyhow::Result<Vec<Sample>> {
+    let start = Instant::now();
+    let mut running = Running::default();
+    let mut samples = Vec::new();
 
-    let alt_start = Instant::now();
-    let output = f(input);
-    let avg_time = runs
-        .iter()
-        .sum::<time::Duration>()
-        .checked_div(runs.len() as u32)
-        .unwrap_or_else(|| alt_start.elapsed());
-
-    BenchRun {
-        output,
-        avg_time,
-        elapsed: start.elapsed(),
-        times,
-        day: info.day,
-        year: info.year,
-        name: info.name,
-        label,
+    loop {
+        let (_, time) = sample(input, params, parse, f)?;
+        running.push(time.total());
+        samples.push(time);
+
+        if start.elapsed() >= ADAPTIVE_BUDGET {
+            return Ok(samples);
+        }
+        if running.count() >= ADAPTIVE_MIN_SAMPLES
+            && running
+                .relative_error()
+                .is_some_and(|it| it < TARGET_RELATIVE_ERROR)
+        {
+            return Ok(samples);
+        }
     }
 }
 
+/// runs the parser of a day, if it has one, and times it
+pub fn parse_input(
+    input: &str,
+    parse: Option<ParseFn>,
+) -> anyhow::Result<(Option<Parsed>, time::Duration)> {
+    let time = Instant::now();
+    let parsed = parse
+        .map(|parse| parse(input).context("failed to parse the input"))
+        .transpose()?;
+    Ok((core::hint::black_box(parsed), time.elapsed()))
+}
+
+fn input_of<'a>(input: &'a str, parsed: Option<&'a Parsed>) -> Input<'a> {
+    parsed.map_or_else(
+        || Input::new(input),
+        |parsed| Input::with_parsed(input, parsed.as_ref()),
+    )
+}
+
+/// runs the solution once on the result of `parse_input` and times it,
+/// so the parts of a day can share one parse
+pub fn run_solution(
+    input: &str,
+    params: &[(&str, &str)],
+    (parsed, parse_time): &(Option<Parsed>, time::Duration),
+    info: &Info,
+    label: String,
+    f: SolutionFn,
+) -> anyhow::Result<BenchRun> {
+    let start = Instant::now();
+    let output = f(&input_of(input, parsed.as_ref()).with_params(params))?;
+    let solve_time = start.elapsed();
+
+    let total = solve_time
+        + parsed
+            .as_ref()
+            .map_or(time::Duration::ZERO, |_| *parse_time);
+    Ok(
+        BenchRun::new(info, label, output, &Stats::once(total), total)
+            .with_phases(parsed.as_ref().map(|_| *parse_time), solve_time),
+    )
+}
+
+/// runs `f`, turning a panic into an error, so a failing entry does not abort the others
+pub fn catch_panic<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .map(|it| (*it).to_owned())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_owned());
+        Err(anyhow::anyhow!("panicked: {msg}"))
+    })
+}
+
+/// like `time_bench` with the number of runs known at runtime
 pub fn time_bench_runt<R>(label: impl Display, times: usize, f: impl Fn() -> R + Send + Sync) -> R
 where
     R: Send + Sync + Debug,
 {
     let start = Instant::now();
     let benched = (0..times)
-        .par_bridge()
         .map(|_| {
             let time = Instant::now();
             let _ = f();
@@ -401,10 +1367,52 @@ where
     );
 
     let result = f();
-    eprintln!("{label} resulted in {result:?}",);
+    eprintln!("{label} resulted in {result:?}");
     result
 }
 
+/// formats a timestamp as `YYYY-MM-DD HH:MM:SS` in UTC
+#[must_use]
+pub fn format_utc(time: std::time::SystemTime) -> String {
+    let secs = time
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |it| it.as_secs());
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+    // civil from days, see <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:0>4}-{month:0>2}-{day:0>2} {:0>2}:{:0>2}:{:0>2}",
+        secs_of_day / 3_600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )
+}
+
+#[test]
+fn format_utc_works() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    assert_eq!(format_utc(UNIX_EPOCH), "1970-01-01 00:00:00");
+    assert_eq!(
+        format_utc(UNIX_EPOCH + Duration::from_hours(463_901)),
+        "2022-12-03 05:00:00"
+    );
+    assert_eq!(
+        format_utc(UNIX_EPOCH + Duration::from_secs(951_782_400 + 86_399)),
+        "2000-02-29 23:59:59"
+    );
+}
+
 /// utility function
 pub fn zip<A: Iterator, B: Iterator>(a: A, b: B) -> impl Iterator<Item = (A::Item, B::Item)> {
     a.zip(b)
@@ -487,3 +1495,82 @@ pub mod map_trait {
         }
     }
 }
+
+#[test]
+fn parse_is_timed_separately() {
+    let info = Info {
+        name: "test",
+        day: 1,
+        year: 2022,
+        bench: BenchTimes::Many(3),
+    };
+    let parse: ParseFn = |data| Ok(Box::new(data.lines().count()));
+    let lines: SolutionFn = |data| Ok((*data.parsed::<usize>()).into());
+
+    let run = time_bench_with(
+        "a\nb",
+        &[],
+        &info,
+        "part1".to_owned(),
+        Some(parse),
+        lines,
+        &info.bench,
+    )
+    .unwrap();
+    assert_eq!(run.output.unwrap().to_string(), "2");
+    assert_eq!(run.times, 3);
+    assert!(run.parse_time.is_some());
+
+    let plain = run_solution(
+        "abc",
+        &[],
+        &parse_input("abc", None).unwrap(),
+        &info,
+        "part2".to_owned(),
+        |data| Ok(data.len().into()),
+    )
+    .unwrap();
+    assert_eq!((plain.parse_time, plain.solve_time), (None, plain.avg_time));
+}
+
+#[test]
+fn failures_are_caught() {
+    let info = Info {
+        name: "test",
+        day: 1,
+        year: 2022,
+        bench: BenchTimes::Default,
+    };
+    let run = |f: SolutionFn| {
+        catch_panic(|| time_bench_solution("", &[], &info, "part1".to_owned(), None, f))
+            .err()
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!(run(|_| anyhow::bail!("bad input")), "bad input");
+    assert_eq!(run(|_| panic!("oops")), "panicked: oops");
+
+    let parse: ParseFn = |_| anyhow::bail!("no numbers");
+    let err = parse_input("", Some(parse)).unwrap_err();
+    assert_eq!(format!("{err:#}"), "failed to parse the input: no numbers");
+}
+
+#[test]
+fn problem_results_convert() {
+    assert_eq!(ProblemResult::from(7u8), ProblemResult::Number(7));
+    assert_eq!(
+        ProblemResult::from(u64::MAX),
+        ProblemResult::Big(i128::from(u64::MAX))
+    );
+    assert_eq!(
+        ProblemResult::from(u128::MAX).to_string(),
+        u128::MAX.to_string()
+    );
+    assert_eq!(ProblemResult::Big(3), ProblemResult::Number(3));
+
+    assert_eq!(ProblemResult::from((1u32, "a")).to_string(), "(1, a)");
+    assert_eq!(ProblemResult::lines("#.\n.#").to_string(), "#.\n.#");
+    assert_eq!(ProblemResult::from(()).to_string(), "-");
+    assert_ne!(ProblemResult::from("1"), ProblemResult::Number(1));
+}
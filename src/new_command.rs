@@ -0,0 +1,137 @@
+//! creates the files of a new day from `template.rs`
+
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::aoc_client::AocClient;
+
+/// compiled into the binary as well, so it stays a valid solution
+const TEMPLATE: &str = include_str!("template.rs");
+
+/// the title from the `<h2>--- Day 1: Calorie Counting ---</h2>` of a puzzle page
+fn parse_title(page: &str) -> Option<String> {
+    let (_, heading) = page.split_once("<h2>")?;
+    let (heading, _) = heading.split_once("</h2>")?;
+    let (_, title) = heading
+        .trim_matches(|it| it == '-' || it == ' ')
+        .split_once(": ")?;
+
+    Some(
+        title
+            .replace("&apos;", "'")
+            .replace("&#39;", "'")
+            .replace("&quot;", "\"")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&amp;", "&"),
+    )
+}
+
+/// downloads the puzzle page and reads its title
+pub fn fetch_title(client: &mut AocClient, year: u16, day: u8) -> anyhow::Result<String> {
+    let path = format!("/{year}/day/{day}");
+    let (status, page) = client.get(&path)?;
+    if status != 200 {
+        anyhow::bail!("GET {} responded with {status}", client.url(&path));
+    }
+
+    parse_title(&page).context("the puzzle page has no title")
+}
+
+/// the template with the date and title filled in
+fn render(year: u16, day: u8, title: &str) -> anyhow::Result<String> {
+    [
+        ("pub const _SOLUTION", "pub const SOLUTION".to_owned()),
+        ("name: \"template\"", format!("name: {title:?}")),
+        ("day: 0,", format!("day: {day},")),
+        ("year: 1970,", format!("year: {year},")),
+        ("input: _EXAMPLE,", "input: EXAMPLE,".to_owned()),
+        (
+            "const _EXAMPLE: &str = \"\";",
+            format!(
+                "const EXAMPLE: &str = include_str!(\"../../inputs/{year}-day{day}-test.txt\");"
+            ),
+        ),
+    ]
+    .into_iter()
+    .try_fold(TEMPLATE.to_owned(), |source, (from, to)| {
+        anyhow::ensure!(source.contains(from), "template.rs has no {from:?}");
+        Ok(source.replacen(from, &to, 1))
+    })
+}
+
+/// writes `{src}/yr{year}/day{day}.rs` and an empty `{inputs}/{year}-day{day}-test.txt`,
+/// fails if the source exists, keeps an existing example.
+/// returns the paths of the source and the example
+pub fn create(
+    src: &Path,
+    inputs: &Path,
+    (year, day): (u16, u8),
+    title: &str,
+) -> anyhow::Result<(PathBuf, PathBuf)> {
+    let source = src.join(format!("yr{year}")).join(format!("day{day}.rs"));
+    let example = inputs.join(format!("{year}-day{day}-test.txt"));
+    let rendered = render(year, day, title)?;
+
+    fs::create_dir_all(source.parent().expect("the source is in a year"))?;
+    let mut file = match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&source)
+    {
+        Ok(file) => file,
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => {
+            anyhow::bail!(
+                "{} exists already, refusing to overwrite it",
+                source.display()
+            )
+        }
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to create {}", source.display()))
+        }
+    };
+    file.write_all(rendered.as_bytes())
+        .with_context(|| format!("failed to write {}", source.display()))?;
+
+    fs::create_dir_all(inputs)?;
+    match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&example)
+    {
+        Ok(_) => {}
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => {}
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to create {}", example.display()))
+        }
+    }
+
+    Ok((source, example))
+}
+
+#[test]
+fn new_day_is_created() {
+    let page = "<article class=\"day-desc\"><h2>--- Day 5: Supply Stacks &amp; Crates ---</h2><p>";
+    assert_eq!(parse_title(page).unwrap(), "Supply Stacks & Crates");
+    assert_eq!(parse_title("<p>no puzzle</p>"), None);
+
+    let dir = std::env::temp_dir().join(format!("aoc-any-new-{}", std::process::id()));
+    let (src, inputs) = (dir.join("src"), dir.join("inputs"));
+
+    let (source, example) = create(&src, &inputs, (2022, 5), "Supply \"Stacks\"").unwrap();
+    let source_text = fs::read_to_string(&source).unwrap();
+    assert!(source_text.contains("pub const SOLUTION"));
+    assert!(source_text.contains(r#"name: "Supply \"Stacks\"""#));
+    assert!(source_text.contains("day: 5,"));
+    assert!(source_text.contains("year: 2022,"));
+    assert!(source_text.contains("../../inputs/2022-day5-test.txt"));
+    assert!(example.exists());
+
+    assert!(create(&src, &inputs, (2022, 5), "other").is_err());
+    assert_eq!(fs::read_to_string(&source).unwrap(), source_text);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,192 @@
+//! reads the block letters that some puzzles draw instead of printing an answer
+
+use anyhow::Context;
+
+/// the letters 6 pixels high, spaced 5 pixels apart
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// the letters 10 pixels high, spaced 8 pixels apart
+const LARGE: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// reads the letters drawn with `#` (or `█`), any other character is a dark pixel
+pub fn read(screen: &str) -> anyhow::Result<String> {
+    let rows = screen
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.chars()
+                .map(|it| matches!(it, '#' | '█'))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    read_grid(&rows)
+}
+
+/// reads the letters from rows of pixels, `true` is lit.
+/// the height picks the font and the width of the cells the letters are drawn in
+pub fn read_grid<R: AsRef<[bool]>>(rows: &[R]) -> anyhow::Result<String> {
+    let (font, cell_width) = match rows.len() {
+        6 => (SMALL, 5),
+        10 => (LARGE, 8),
+        height => anyhow::bail!("no letters are {height} pixels high, only 6 and 10"),
+    };
+
+    let width = rows.iter().map(|it| it.as_ref().len()).max().unwrap_or(0);
+    let lit = |x: usize| rows.iter().any(|row| row.as_ref().get(x) == Some(&true));
+
+    let mut letters = String::new();
+    for start in (0..width).step_by(cell_width) {
+        // letters can fill their cell, like the small 'Y', so they are not split at dark columns.
+        // the dark columns around a letter in its cell are trimmed, dark cells are skipped
+        let cell = start..(start + cell_width).min(width);
+        let (Some(first), Some(last)) =
+            (cell.clone().find(|&x| lit(x)), cell.rev().find(|&x| lit(x)))
+        else {
+            continue;
+        };
+
+        let glyph = rows
+            .iter()
+            .map(|row| {
+                (first..=last)
+                    .map(|x| {
+                        if row.as_ref().get(x) == Some(&true) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let letter = font
+            .iter()
+            .find(|(_, it)| *it == glyph)
+            .map(|(letter, _)| *letter)
+            .with_context(|| format!("unknown letter at column {start}:\n{glyph}"))?;
+        letters.push(letter);
+    }
+
+    if letters.is_empty() {
+        anyhow::bail!("the screen is dark");
+    }
+    Ok(letters)
+}
+
+#[test]
+fn ocr_works() {
+    let screen = indoc::indoc! {"
+        #..#.####.###..###.
+        #..#.#....#..#.#..#
+        ####.###..###..#..#
+        #..#.#....#..#.###.
+        #..#.#....#..#.#...
+        #..#.####.###..#...
+    "};
+    assert_eq!(read(screen).unwrap(), "HEBP");
+
+    // every letter of a font in one word, each in a cell of the font's width
+    let word = |font: &[(char, &str)], height, cell_width| {
+        (0..height)
+            .map(|y| {
+                font.iter()
+                    .map(|(_, glyph)| {
+                        let row = glyph.lines().nth(y).unwrap();
+                        format!("{row:.<cell_width$}")
+                    })
+                    .collect::<Vec<_>>()
+                    .concat()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    // the small 'Y' fills its cell, and is followed by the 'Z'
+    assert_eq!(read(&word(SMALL, 6, 5)).unwrap(), "ABCEFGHIJKLOPRSUYZ");
+    assert_eq!(read(&word(LARGE, 10, 8)).unwrap(), "ABCEFGHJKLNPRXZ");
+
+    assert!(read("#\n#").is_err());
+    assert!(read(&"#####.\n".repeat(6)).is_err());
+}
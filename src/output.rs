@@ -0,0 +1,371 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use clap::ValueEnum;
+use cli_table::{format::Justify, Table, WithTitle};
+use serde::Serialize;
+
+use crate::answers::Verdict;
+use crate::types::{BenchRun, ProblemResult};
+
+/// how the results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// a result as it is written by the machine readable formats, durations are in nanoseconds
+#[derive(Serialize)]
+struct Row<'a> {
+    year: u16,
+    day: u8,
+    name: &'a str,
+    label: &'a str,
+    avg_ns: u128,
+    /// `None` for days without a parser
+    parse_ns: Option<u128>,
+    solve_ns: u128,
+    median_ns: u128,
+    stddev_ns: u128,
+    min_ns: u128,
+    max_ns: u128,
+    p95_ns: u128,
+    elapsed_ns: u128,
+    times: usize,
+    outliers: usize,
+    /// `None` without the `count-allocations` feature
+    allocations: Option<usize>,
+    allocated_bytes: Option<usize>,
+    peak_bytes: Option<usize>,
+    result: serde_json::Value,
+    /// the result as the table shows it, for csv and markdown
+    #[serde(skip)]
+    display: String,
+    status: &'static str,
+    expected: Option<&'a str>,
+    /// why a failed entry failed
+    error: Option<String>,
+}
+
+impl<'a> From<&'a BenchRun> for Row<'a> {
+    fn from(run: &'a BenchRun) -> Self {
+        let (status, expected) = match &run.verdict {
+            _ if run.output.is_err() => ("failed", None),
+            Verdict::Correct => ("correct", None),
+            Verdict::Wrong(expected) => ("wrong", Some(expected.as_str())),
+            Verdict::Unknown => ("unknown", None),
+        };
+
+        Self {
+            year: run.year,
+            day: run.day,
+            name: run.name,
+            label: &run.label,
+            avg_ns: run.avg_time.as_nanos(),
+            parse_ns: run.parse_time.map(|it| it.as_nanos()),
+            solve_ns: run.solve_time.as_nanos(),
+            median_ns: run.median.as_nanos(),
+            stddev_ns: run.stddev.as_nanos(),
+            min_ns: run.min.as_nanos(),
+            max_ns: run.max.as_nanos(),
+            p95_ns: run.p95.as_nanos(),
+            elapsed_ns: run.elapsed.as_nanos(),
+            times: run.times,
+            outliers: run.outliers,
+            allocations: run.alloc_count,
+            allocated_bytes: run.alloc_bytes,
+            peak_bytes: run.peak_bytes,
+            result: run
+                .output
+                .as_ref()
+                .map_or(serde_json::Value::Null, json_value),
+            display: run
+                .output
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            status,
+            expected,
+            error: run.output.as_ref().err().map(|err| format!("{err:#}")),
+        }
+    }
+}
+
+/// numbers stay numbers, as long as every json parser reads them exactly
+fn json_value(result: &ProblemResult) -> serde_json::Value {
+    match result {
+        ProblemResult::Number(n) => (*n).into(),
+        ProblemResult::Lines(lines) => lines.clone().into(),
+        ProblemResult::Pair(pair) => vec![json_value(&pair.0), json_value(&pair.1)].into(),
+        ProblemResult::Unit => serde_json::Value::Null,
+        other @ (ProblemResult::Big(_) | ProblemResult::Text(_) | ProblemResult::Other(_)) => {
+            other.to_string().into()
+        }
+    }
+}
+
+const COLUMNS: &[&str] = &[
+    "year",
+    "day",
+    "name",
+    "label",
+    "avg_ns",
+    "parse_ns",
+    "solve_ns",
+    "median_ns",
+    "stddev_ns",
+    "min_ns",
+    "max_ns",
+    "p95_ns",
+    "elapsed_ns",
+    "times",
+    "outliers",
+    "allocations",
+    "allocated_bytes",
+    "peak_bytes",
+    "result",
+    "status",
+    "expected",
+    "error",
+];
+
+impl Row<'_> {
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            self.name.to_owned(),
+            self.label.to_owned(),
+            self.avg_ns.to_string(),
+            self.parse_ns.map(|it| it.to_string()).unwrap_or_default(),
+            self.solve_ns.to_string(),
+            self.median_ns.to_string(),
+            self.stddev_ns.to_string(),
+            self.min_ns.to_string(),
+            self.max_ns.to_string(),
+            self.p95_ns.to_string(),
+            self.elapsed_ns.to_string(),
+            self.times.to_string(),
+            self.outliers.to_string(),
+            self.allocations
+                .map(|it| it.to_string())
+                .unwrap_or_default(),
+            self.allocated_bytes
+                .map(|it| it.to_string())
+                .unwrap_or_default(),
+            self.peak_bytes.map(|it| it.to_string()).unwrap_or_default(),
+            self.display.clone(),
+            self.status.to_owned(),
+            self.expected.unwrap_or_default().to_owned(),
+            self.error.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+/// the parts of a year in the results, summed up
+#[derive(Table)]
+struct YearTotal {
+    #[table(title = "year", justify = "Justify::Right")]
+    year: u16,
+    #[table(title = "days", justify = "Justify::Right")]
+    days: usize,
+    #[table(title = "parts", justify = "Justify::Right")]
+    parts: usize,
+    #[table(title = "failed", justify = "Justify::Right")]
+    failed: usize,
+    #[table(title = "total avg", display_fn = "display_duration")]
+    avg: Duration,
+    #[table(title = "total median", display_fn = "display_duration")]
+    median: Duration,
+}
+
+fn display_duration(inp: &Duration) -> impl std::fmt::Display {
+    format!("{inp:?}")
+}
+
+/// the totals of part 1 and part 2 of each year, the other entries solve the same parts again.
+/// both parts of a day parse the input, so its parse time is counted only once
+fn year_totals(runs: &[BenchRun]) -> Vec<YearTotal> {
+    let parts = runs
+        .iter()
+        .filter(|it| matches!(it.label.as_str(), "part1" | "part2"))
+        .collect::<Vec<_>>();
+
+    let mut years = parts.iter().map(|it| it.year).collect::<Vec<_>>();
+    years.sort_unstable();
+    years.dedup();
+
+    years
+        .into_iter()
+        .map(|year| {
+            let parts = parts
+                .iter()
+                .filter(|it| it.year == year)
+                .collect::<Vec<_>>();
+            let mut days = parts.iter().map(|it| it.day).collect::<Vec<_>>();
+            days.sort_unstable();
+            days.dedup();
+
+            let parse = days
+                .iter()
+                .filter_map(|&day| {
+                    parts
+                        .iter()
+                        .filter(|it| it.day == day)
+                        .find_map(|it| it.parse_time)
+                })
+                .sum::<Duration>();
+            let without_parse = |time: Duration, part: &BenchRun| {
+                time.saturating_sub(part.parse_time.unwrap_or_default())
+            };
+
+            YearTotal {
+                year,
+                days: days.len(),
+                parts: parts.len(),
+                failed: parts.iter().filter(|it| it.output.is_err()).count(),
+                avg: parse + parts.iter().map(|it| it.solve_time).sum::<Duration>(),
+                median: parse
+                    + parts
+                        .iter()
+                        .map(|it| without_parse(it.median, it))
+                        .sum::<Duration>(),
+            }
+        })
+        .collect()
+}
+
+/// prints the results to stdout, tables spanning several days end with the totals of each year
+pub fn print_runs(runs: &[BenchRun], format: Format) -> anyhow::Result<()> {
+    if format == Format::Table {
+        cli_table::print_stdout(runs.with_title()).map_err(|_| anyhow!("Failed to print table"))?;
+
+        let totals = year_totals(runs);
+        if totals.iter().any(|it| it.days > 1) || totals.len() > 1 {
+            cli_table::print_stdout(totals.with_title())
+                .map_err(|_| anyhow!("Failed to print table"))?;
+        }
+        return Ok(());
+    }
+
+    print!("{}", format_runs(runs, format)?);
+    Ok(())
+}
+
+fn format_runs(runs: &[BenchRun], format: Format) -> anyhow::Result<String> {
+    let rows = runs.iter().map(Row::from).collect::<Vec<_>>();
+    let mut out = String::new();
+
+    match format {
+        Format::Table => unreachable!("tables are printed by cli_table"),
+        Format::Json => {
+            out = serde_json::to_string_pretty(&rows)?;
+            out.push('\n');
+        }
+        Format::Csv => {
+            writeln!(out, "{}", COLUMNS.join(","))?;
+            for row in &rows {
+                let fields = row
+                    .fields()
+                    .iter()
+                    .map(|it| csv_field(it))
+                    .collect::<Vec<_>>();
+                writeln!(out, "{}", fields.join(","))?;
+            }
+        }
+        Format::Markdown => {
+            writeln!(out, "| {} |", COLUMNS.join(" | "))?;
+            writeln!(out, "|{}", "---|".repeat(COLUMNS.len()))?;
+            for row in &rows {
+                let fields = row
+                    .fields()
+                    .iter()
+                    .map(|it| it.replace('|', "\\|").replace('\n', "<br>"))
+                    .collect::<Vec<_>>();
+                writeln!(out, "| {} |", fields.join(" | "))?;
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// quotes fields containing separators, quotes or line breaks
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[test]
+fn formats_work() {
+    let info = crate::Info {
+        name: "Calorie Counting",
+        day: 1,
+        year: 2022,
+        bench: crate::BenchTimes::Default,
+    };
+    let time = Duration::from_micros(3);
+    let mut runs = vec![
+        BenchRun::new(
+            &info,
+            "part1".to_owned(),
+            ProblemResult::Number(66_186),
+            &crate::Stats::once(time),
+            time,
+        ),
+        BenchRun::new(
+            &info,
+            "part2".to_owned(),
+            ProblemResult::Other(Box::new((1, "b"))),
+            &crate::Stats::once(time),
+            time,
+        )
+        .with_phases(Some(Duration::from_micros(1)), Duration::from_micros(2)),
+    ];
+    runs[0].verdict = Verdict::Correct;
+    runs[1].verdict = Verdict::Wrong("x|y".to_owned());
+
+    let json =
+        serde_json::from_str::<serde_json::Value>(&format_runs(&runs, Format::Json).unwrap())
+            .unwrap();
+    assert_eq!(json[0]["result"], 66_186);
+    assert_eq!(json[0]["elapsed_ns"], 3_000);
+    assert_eq!(json[0]["status"], "correct");
+    assert_eq!(json[0]["parse_ns"], serde_json::Value::Null);
+    assert_eq!(json[1]["parse_ns"], 1_000);
+    assert_eq!(json[1]["solve_ns"], 2_000);
+    assert_eq!(json[1]["expected"], "x|y");
+
+    let csv = format_runs(&runs, Format::Csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], COLUMNS.join(","));
+    assert!(lines[1].ends_with(",66186,correct,,"));
+    assert!(lines[2].ends_with(r#","(1, ""b"")",wrong,x|y,"#));
+
+    let totals = year_totals(&runs);
+    assert_eq!((totals.len(), totals[0].days, totals[0].parts), (1, 1, 2));
+    assert_eq!(totals[0].avg, Duration::from_micros(6));
+
+    let markdown = format_runs(&runs, Format::Markdown).unwrap();
+    assert!(markdown
+        .lines()
+        .nth(3)
+        .unwrap()
+        .ends_with("| wrong | x\\|y |  |"));
+
+    // the parts share the parse of the day, the totals only count it once
+    let first = runs
+        .remove(0)
+        .with_phases(Some(Duration::from_micros(1)), Duration::from_micros(2));
+    let totals = year_totals(&[first, runs.remove(0)]);
+    assert_eq!(totals[0].avg, Duration::from_micros(5));
+    assert_eq!(totals[0].median, Duration::from_micros(5));
+}
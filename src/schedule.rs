@@ -0,0 +1,62 @@
+//! how benchmarks are scheduled, the size of the rayon pool and the core they run on
+
+use anyhow::Context;
+
+/// sizes the global rayon pool that solutions use, before anything runs on it
+pub fn set_threads(threads: usize) -> anyhow::Result<()> {
+    anyhow::ensure!(threads > 0, "--threads needs at least one thread");
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .context("failed to size the rayon pool")
+}
+
+/// pins the calling thread to a core, so it is not moved between cores while it is timed
+#[cfg(target_os = "linux")]
+pub fn pin_current_thread(core: usize) -> anyhow::Result<()> {
+    #[allow(clippy::cast_sign_loss)]
+    let cores = libc::CPU_SETSIZE as usize;
+    anyhow::ensure!(core < cores, "there is no core {core}");
+
+    // SAFETY: the set is zeroed before it is used, and only the calling thread is changed
+    let result = unsafe {
+        let mut set = std::mem::zeroed::<libc::cpu_set_t>();
+        libc::CPU_ZERO(&mut set);
+        libc::CPU_SET(core, &mut set);
+        libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &raw const set)
+    };
+    if result != 0 {
+        return Err(std::io::Error::last_os_error())
+            .with_context(|| format!("failed to pin the benchmark to core {core}"));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn pin_current_thread(_core: usize) -> anyhow::Result<()> {
+    anyhow::bail!("--pin is only supported on linux")
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn pinning_fails_on_missing_cores() {
+    std::thread::spawn(|| {
+        // pins to a core the tests may run on, core 0 is not in every cpuset.
+        // SAFETY: the set is zeroed before it is used, and only read after it is filled in
+        let allowed = unsafe {
+            let mut set = std::mem::zeroed::<libc::cpu_set_t>();
+            libc::CPU_ZERO(&mut set);
+            assert_eq!(
+                libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &raw mut set),
+                0
+            );
+            #[allow(clippy::cast_sign_loss)]
+            (0..libc::CPU_SETSIZE as usize).find(|&core| libc::CPU_ISSET(core, &set))
+        };
+
+        pin_current_thread(allowed.unwrap()).unwrap();
+        assert!(pin_current_thread(usize::MAX).is_err());
+    })
+    .join()
+    .unwrap();
+}
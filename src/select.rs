@@ -0,0 +1,303 @@
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+
+use crate::cli::Selection;
+use crate::examples::Example;
+use crate::types::{Info, ParseFn, Run, Solution, SolutionFn};
+
+/// one function of a `Solution` that can be run
+pub struct Entry {
+    /// `"{year} day{NN}: {label}"`, what queries are matched against
+    pub name: String,
+    pub label: &'static str,
+    pub f: SolutionFn,
+    /// the parser of the day, shared by all of its entries
+    pub parse: Option<ParseFn>,
+    /// the examples of the day, the ones with an answer for `label` are checked
+    pub examples: &'static [Example],
+    /// the parameters of the day with their defaults
+    pub params: &'static [(&'static str, &'static str)],
+    pub info: &'static Info,
+    /// part 1, part 2 and the others with `Run::Yes` run when nothing is queried
+    pub default: bool,
+    /// how well the entry matched the queries, higher is better
+    pub score: i64,
+}
+
+/// all entries of a Solution, the others first, then part 2 and part 1
+pub fn entries(solution: &'static Solution) -> Vec<Entry> {
+    let parts = solution.part2.map_or_else(
+        || vec![("part1", solution.part1, true)],
+        |part2| vec![("part2", part2, true), ("part1", solution.part1, true)],
+    );
+
+    let others = solution
+        .other
+        .iter()
+        .map(|(label, f, run)| (*label, *f, matches!(run, Run::Yes)));
+
+    others
+        .chain(parts)
+        .map(|(label, f, default)| Entry {
+            name: format!(
+                "{} day{:0>2}: {label}",
+                solution.info.year, solution.info.day
+            ),
+            label,
+            f,
+            parse: solution.parse,
+            examples: solution.examples,
+            params: solution.params,
+            info: &solution.info,
+            default,
+            score: 0,
+        })
+        .collect()
+}
+
+impl Entry {
+    /// the overrides, followed by the declared defaults, so the overrides win
+    #[must_use]
+    pub fn params<'a>(&self, overrides: &[(&'a str, &'a str)]) -> Vec<(&'a str, &'a str)> {
+        overrides.iter().chain(self.params).copied().collect()
+    }
+}
+
+impl Selection {
+    /// the entries of `days` matching the filters and any of the queries,
+    /// sorted by year, day and label
+    pub fn select(&self, days: &'static [Solution]) -> Vec<Entry> {
+        let mut selected = days
+            .iter()
+            .filter(|day| self.year.is_empty() || self.year.contains(&day.info.year))
+            .filter(|day| self.day.is_empty() || self.day.contains(&day.info.day))
+            .flat_map(entries)
+            .filter(|entry| {
+                self.part.as_deref().is_none_or(|part| match part {
+                    "1" => entry.label == "part1",
+                    "2" => entry.label == "part2",
+                    label => entry.label == label,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if self.queries.is_empty() {
+            selected.retain(|it| it.default);
+        } else {
+            selected = self.rank(selected);
+        }
+
+        selected.sort_by(|a, b| {
+            (a.info.year, a.info.day, a.label).cmp(&(b.info.year, b.info.day, b.label))
+        });
+        selected
+    }
+
+    /// keeps the entries matched by any query, scored by their best match.
+    /// with `--best`, only the entries sharing the top score of a query are kept,
+    /// where entries that run by default win ties
+    fn rank(&self, entries: Vec<Entry>) -> Vec<Entry> {
+        let matcher = SkimMatcherV2::default();
+        let queries = self
+            .queries
+            .iter()
+            .map(|it| normalize_query(it))
+            .collect::<Vec<_>>();
+
+        // scores[entry][query]
+        let scores = entries
+            .iter()
+            .map(|entry| {
+                queries
+                    .iter()
+                    .map(|query| self.score(&matcher, entry, query))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let top = (0..queries.len())
+            .map(|query| {
+                entries
+                    .iter()
+                    .zip(&scores)
+                    .filter_map(|(entry, it)| Some((it[query]?, entry.default)))
+                    .max()
+            })
+            .collect::<Vec<_>>();
+
+        entries
+            .into_iter()
+            .zip(scores)
+            .filter_map(|(mut entry, scores)| {
+                let kept = scores.iter().zip(&top).filter_map(|(score, top)| {
+                    let score = (*score)?;
+                    crate::some_if! {
+                        !self.best || Some((score, entry.default)) == *top => score
+                    }
+                });
+
+                entry.score = kept.max()?;
+                Some(entry)
+            })
+            .collect()
+    }
+
+    /// prints the selected names, with their score when queries were fuzzy matched
+    pub fn print(&self, selected: &[Entry]) {
+        let scored = !self.queries.is_empty() && !self.exact && !self.prefix;
+
+        for entry in selected {
+            if scored {
+                println!("{:>4}  {}", entry.score, entry.name);
+            } else {
+                println!("{}", entry.name);
+            }
+        }
+    }
+
+    fn score(&self, matcher: &SkimMatcherV2, entry: &Entry, query: &str) -> Option<i64> {
+        // the name without the year, e.g. "day08: part1"
+        let short_name = entry.name.split_once(' ').map_or("", |it| it.1);
+
+        if self.exact {
+            crate::some_if! { entry.name == query || entry.label == query => 0 }
+        } else if self.prefix {
+            crate::some_if! {
+                [entry.name.as_str(), short_name, entry.label]
+                    .iter()
+                    .any(|it| it.starts_with(query)) => 0
+            }
+        } else {
+            matcher.fuzzy_match(&entry.name, query)
+        }
+    }
+}
+
+/// pads single digit days, so "day1" matches "day01" instead of "day10"
+fn normalize_query(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len() + 1);
+    let mut rest = query;
+
+    while let Some(index) = rest.find("day") {
+        let (before, after) = rest.split_at(index + 3);
+        normalized.push_str(before);
+
+        let mut digits = after.chars().take_while(char::is_ascii_digit);
+        if let (Some(digit), None) = (digits.next(), digits.next()) {
+            normalized.push('0');
+            normalized.push(digit);
+            rest = &after[1..];
+        } else {
+            rest = after;
+        }
+    }
+
+    normalized.push_str(rest);
+    normalized
+}
+
+#[test]
+fn normalize_query_works() {
+    assert_eq!(normalize_query("day1"), "day01");
+    assert_eq!(normalize_query("2022 day1: part2"), "2022 day01: part2");
+    assert_eq!(normalize_query("day14"), "day14");
+    assert_eq!(normalize_query("days"), "days");
+    assert_eq!(normalize_query("part1"), "part1");
+}
+
+#[test]
+fn select_works() {
+    use crate::types::{BenchTimes, ProblemResult};
+
+    const DAYS: &[Solution] = &[
+        Solution {
+            part1: |_| Ok(ProblemResult::Number(1)),
+            part2: None,
+            parse: None,
+            info: Info {
+                name: "a",
+                day: 1,
+                year: 2022,
+                bench: BenchTimes::Default,
+            },
+            params: &[],
+            examples: &[],
+            other: &[],
+        },
+        Solution {
+            part1: |_| Ok(ProblemResult::Number(1)),
+            part2: Some(|_| Ok(ProblemResult::Number(2))),
+            parse: None,
+            info: Info {
+                name: "b",
+                day: 14,
+                year: 2022,
+                bench: BenchTimes::Default,
+            },
+            params: &[],
+            examples: &[],
+            other: &[("slow part2", |_| Ok(ProblemResult::Number(2)), Run::No)],
+        },
+    ];
+
+    let names = |select: Selection| {
+        select
+            .select(DAYS)
+            .into_iter()
+            .map(|it| it.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        names(Selection::default()),
+        [
+            "2022 day01: part1",
+            "2022 day14: part1",
+            "2022 day14: part2"
+        ]
+    );
+    assert_eq!(
+        names(Selection {
+            day: vec![14],
+            part: Some("2".into()),
+            ..Default::default()
+        }),
+        ["2022 day14: part2"]
+    );
+    assert_eq!(
+        names(Selection {
+            queries: vec!["slow".into()],
+            ..Default::default()
+        }),
+        ["2022 day14: slow part2"]
+    );
+    assert_eq!(
+        names(Selection {
+            queries: vec!["part1".into()],
+            exact: true,
+            year: vec![2022],
+            ..Default::default()
+        }),
+        ["2022 day01: part1", "2022 day14: part1"]
+    );
+    assert_eq!(
+        names(Selection {
+            queries: vec!["day1".into()],
+            prefix: true,
+            ..Default::default()
+        }),
+        ["2022 day01: part1"]
+    );
+    assert_eq!(
+        names(Selection {
+            queries: vec!["day14".into()],
+            best: true,
+            ..Default::default()
+        }),
+        ["2022 day14: part1", "2022 day14: part2"]
+    );
+    assert!(names(Selection {
+        year: vec![2021],
+        ..Default::default()
+    })
+    .is_empty());
+}
@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// summary of the timings of a benchmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    /// 95% of the samples were at most this slow
+    pub p95: Duration,
+    /// samples outside of the Tukey fences, 1.5 interquartile ranges beyond the quartiles
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// `None` without samples
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+        let nanos = sorted
+            .iter()
+            .map(|it| it.as_nanos() as f64)
+            .collect::<Vec<_>>();
+
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|it| (it - mean).powi(2)).sum::<f64>() / (nanos.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        let (q1, q3) = (quantile(&nanos, 0.25), quantile(&nanos, 0.75));
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .filter(|&&it| it < q1 - fence || it > q3 + fence)
+            .count();
+
+        Some(Self {
+            samples: sorted.len(),
+            mean: from_nanos(mean),
+            median: from_nanos(quantile(&nanos, 0.5)),
+            min,
+            max,
+            stddev: from_nanos(variance.sqrt()),
+            p95: from_nanos(quantile(&nanos, 0.95)),
+            outliers,
+        })
+    }
+
+    /// the stats of a single run
+    #[must_use]
+    pub const fn once(time: Duration) -> Self {
+        Self {
+            samples: 1,
+            mean: time,
+            median: time,
+            min: time,
+            max: time,
+            stddev: Duration::ZERO,
+            p95: time,
+            outliers: 0,
+        }
+    }
+}
+
+#[allow(clippy::cast_sign_loss)]
+const fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// linearly interpolated quantile of sorted values
+#[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (pos.floor() as usize, pos.ceil() as usize);
+    (sorted[upper] - sorted[lower]).mul_add(pos - lower as f64, sorted[lower])
+}
+
+/// mean and variance of a growing set of samples, with Welford's algorithm
+#[derive(Debug, Default)]
+pub struct Running {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Running {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn push(&mut self, sample: Duration) {
+        let sample = sample.as_secs_f64();
+        self.count += 1;
+        let delta = sample - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (sample - self.mean);
+    }
+
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// standard error of the mean relative to the mean, `None` with less than two samples
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn relative_error(&self) -> Option<f64> {
+        if self.count < 2 || self.mean <= 0.0 {
+            return None;
+        }
+
+        let variance = self.m2 / (self.count - 1) as f64;
+        Some((variance / self.count as f64).sqrt() / self.mean)
+    }
+}
+
+#[test]
+fn stats_work() {
+    let micros = |it: &[u64]| {
+        it.iter()
+            .map(|&it| Duration::from_micros(it))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(Stats::from_samples(&[]), None);
+
+    let stats = Stats::from_samples(&micros(&[10, 12, 11, 13, 100, 9, 10, 11])).unwrap();
+    assert_eq!(stats.samples, 8);
+    assert_eq!(stats.min, Duration::from_micros(9));
+    assert_eq!(stats.max, Duration::from_micros(100));
+    assert_eq!(stats.median, Duration::from_micros(11));
+    assert_eq!(stats.mean.as_nanos(), 22_000);
+    assert_eq!(stats.outliers, 1);
+    assert!(stats.p95 > Duration::from_micros(13) && stats.p95 < Duration::from_micros(100));
+
+    let once = Stats::from_samples(&micros(&[5])).unwrap();
+    assert_eq!(once, Stats::once(Duration::from_micros(5)));
+
+    let mut running = Running::default();
+    assert_eq!(running.relative_error(), None);
+    for sample in micros(&[10, 10, 10]) {
+        running.push(sample);
+    }
+    assert_eq!(running.count(), 3);
+    assert!(running.relative_error().unwrap() < 1e-9);
+}
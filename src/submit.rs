@@ -0,0 +1,382 @@
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::aoc_client::AocClient;
+use crate::input_source::FetchError;
+
+/// every answer that was submitted, one json object per line
+const HISTORY_LOCATION: &str = "./.cache/submissions.jsonl";
+
+/// how the site judged a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint in which direction
+    Wrong,
+    /// answered too recently, holds the seconds left to wait
+    Wait(u64),
+    /// the part is solved already, or not unlocked yet
+    AlreadySolved,
+    /// holds the start of the message, the site might have changed its wording
+    Unknown(String),
+}
+
+impl Outcome {
+    /// parses the message on the page the site responds with
+    #[must_use]
+    pub fn parse(page: &str) -> Self {
+        let message = page
+            .split_once("<article>")
+            .and_then(|(_, it)| it.split_once("</article>"))
+            .map_or(page, |it| it.0);
+
+        if message.contains("That's the right answer") {
+            Self::Correct
+        } else if message.contains("your answer is too high") {
+            Self::TooHigh
+        } else if message.contains("your answer is too low") {
+            Self::TooLow
+        } else if message.contains("That's not the right answer") {
+            Self::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Self::Wait(parse_wait(message).unwrap_or(60))
+        } else if message.contains("Did you already complete it") {
+            Self::AlreadySolved
+        } else {
+            Self::Unknown(strip_tags(message).chars().take(200).collect())
+        }
+    }
+
+    /// whether the answer must not be submitted again
+    #[must_use]
+    pub const fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "wrong, too high"),
+            Self::TooLow => write!(f, "wrong, too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::Wait(secs) => write!(
+                f,
+                "answered too recently, wait {:?}",
+                Duration::from_secs(*secs)
+            ),
+            Self::AlreadySolved => write!(f, "already solved, or not unlocked yet"),
+            Self::Unknown(message) => write!(f, "unknown response: {message}"),
+        }
+    }
+}
+
+/// reads the seconds from "You have 1m 30s left to wait"
+fn parse_wait(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+
+    time.split_whitespace().try_fold(0, |secs, part| {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number = number.parse::<u64>().ok()?;
+        match unit {
+            "h" => Some(secs + number * 3_600),
+            "m" => Some(secs + number * 60),
+            "s" => Some(secs + number),
+            _ => None,
+        }
+    })
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub level: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// seconds since the unix epoch
+    pub time: u64,
+}
+
+/// the answers submitted so far, so known wrong answers are not submitted twice
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// loads the history from `./.cache/submissions.jsonl`
+    pub fn open() -> anyhow::Result<Self> {
+        Self::load(HISTORY_LOCATION)
+    }
+
+    pub fn load(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+
+        let submissions = match fs::read_to_string(&path) {
+            Ok(ser) => ser
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()
+                .with_context(|| format!("invalid submission in {}", path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()))
+            }
+        };
+
+        Ok(Self { path, submissions })
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[must_use]
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// why the answer should not be submitted, judging by earlier submissions
+    #[must_use]
+    pub fn refusal(&self, year: u16, day: u8, level: u8, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+
+        self.submissions
+            .iter()
+            .filter(|it| (it.year, it.day, it.level) == (year, day, level))
+            .find_map(|earlier| {
+                let bound = number.zip(earlier.answer.parse::<i128>().ok());
+
+                match (&earlier.outcome, bound) {
+                    (Outcome::Correct, _) => Some(format!(
+                        "part {level} was already solved with {}",
+                        earlier.answer
+                    )),
+                    (outcome, _) if outcome.is_wrong() && earlier.answer == answer => {
+                        Some(format!("{answer} was already submitted: {outcome}"))
+                    }
+                    (Outcome::TooHigh, Some((number, high))) if number >= high => {
+                        Some(format!("{high} was already too high"))
+                    }
+                    (Outcome::TooLow, Some((number, low))) if number <= low => {
+                        Some(format!("{low} was already too low"))
+                    }
+                    _ => None,
+                }
+            })
+    }
+
+    /// appends the submission to the history file
+    pub fn record(&mut self, submission: Submission) -> anyhow::Result<()> {
+        let write = || -> anyhow::Result<()> {
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            writeln!(file, "{}", serde_json::to_string(&submission)?)?;
+            Ok(())
+        };
+
+        write().with_context(|| format!("failed to write {}", self.path.display()))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+/// submits the answer for a part, unless the history shows it is wrong,
+/// and records the outcome
+pub fn submit(
+    client: &mut AocClient,
+    history: &mut History,
+    (year, day, level): (u16, u8, u8),
+    answer: &str,
+) -> anyhow::Result<Outcome> {
+    if let Some(reason) = history.refusal(year, day, level, answer) {
+        anyhow::bail!("refusing to submit {answer} for {year} day {day} part {level}: {reason}");
+    }
+
+    let path = format!("/{year}/day/{day}/answer");
+    let (status, body) =
+        client.post_form(&path, &[("level", &level.to_string()), ("answer", answer)])?;
+
+    if status != 200 {
+        // an error status means the same here as when downloading inputs
+        let err = FetchError::check_response(status, "").expect_err("status is not 200");
+        return Err(err)
+            .with_context(|| format!("POST {} responded with {status}", client.url(&path)));
+    }
+
+    let outcome = Outcome::parse(&body);
+
+    // waiting says nothing about the answer
+    if !matches!(outcome, Outcome::Wait(_) | Outcome::Unknown(_)) {
+        history.record(Submission {
+            year,
+            day,
+            level,
+            answer: answer.to_owned(),
+            outcome: outcome.clone(),
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |it| it.as_secs()),
+        })?;
+    }
+
+    Ok(outcome)
+}
+
+#[test]
+fn outcome_parses() {
+    let page = |message: &str| format!("<main>\n<article><p>{message}</p></article>\n</main>");
+
+    assert_eq!(
+        Outcome::parse(&page(
+            "That's the right answer!  You are one gold star closer."
+        )),
+        Outcome::Correct
+    );
+    assert_eq!(
+        Outcome::parse(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.")),
+        Outcome::TooHigh
+    );
+    assert_eq!(
+        Outcome::parse(&page(
+            "That's not the right answer; your answer is too low."
+        )),
+        Outcome::TooLow
+    );
+    assert_eq!(
+        Outcome::parse(&page("That's not the right answer.  If you're stuck, ...")),
+        Outcome::Wrong
+    );
+    assert_eq!(
+        Outcome::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 3s left to wait.")),
+        Outcome::Wait(243)
+    );
+    assert_eq!(
+        Outcome::parse(&page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+        Outcome::AlreadySolved
+    );
+    assert_eq!(
+        Outcome::parse(&page("Something <em>new</em>.")),
+        Outcome::Unknown("Something new.".to_owned())
+    );
+}
+
+#[test]
+fn submit_against_local_site() {
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+
+    /// answers each request with the next page, returns the requests it got
+    fn serve(pages: Vec<&'static str>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            pages
+                .into_iter()
+                .map(|page| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:")
+                        {
+                            content_length = len.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    request.push_str(&String::from_utf8(body).unwrap());
+
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                        page.len()
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+
+    let (base_url, site) = serve(vec![
+        "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        "<article><p>That's the right answer!</p></article>",
+    ]);
+
+    let path =
+        std::env::temp_dir().join(format!("aoc-any-submissions-{}.jsonl", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut client = AocClient::local(&base_url, "token");
+    let mut history = History::load(&path).unwrap();
+
+    assert_eq!(
+        submit(&mut client, &mut history, (2022, 1, 1), "70000").unwrap(),
+        Outcome::TooHigh
+    );
+    // refused without a request, from the recorded outcome
+    assert!(submit(&mut client, &mut history, (2022, 1, 1), "70000").is_err());
+    assert!(submit(&mut client, &mut history, (2022, 1, 1), "80000").is_err());
+    assert_eq!(
+        submit(&mut client, &mut history, (2022, 1, 1), "66186").unwrap(),
+        Outcome::Correct
+    );
+    assert!(submit(&mut client, &mut history, (2022, 1, 1), "1").is_err());
+
+    let requests = site.join().unwrap();
+    assert!(requests[0].starts_with("POST /2022/day/1/answer "));
+    assert!(requests[0].contains("session=token"));
+    assert!(requests[0].ends_with("level=1&answer=70000"));
+    assert!(requests[1].ends_with("level=1&answer=66186"));
+
+    let history = History::load(&path).unwrap();
+    assert_eq!(history.submissions().len(), 2);
+    assert_eq!(history.submissions()[1].outcome, Outcome::Correct);
+
+    fs::remove_file(&path).unwrap();
+}
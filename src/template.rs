@@ -1,4 +1,4 @@
-use aoc_any::{BenchTimes, Info, Solution};
+use aoc_any::{BenchTimes, Example, Info, Solution};
 
 pub const _SOLUTION: Solution = Solution {
     info: Info {
@@ -9,5 +9,16 @@ pub const _SOLUTION: Solution = Solution {
     },
     part1: |_| todo!(),
     part2: None,
+    parse: None,
+    params: &[],
+    examples: &[Example {
+        name: "example",
+        input: _EXAMPLE,
+        // the answers from the puzzle text, like ("part1", "42")
+        answers: &[],
+        params: &[],
+    }],
     other: &[],
 };
+
+const _EXAMPLE: &str = "";
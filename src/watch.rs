@@ -0,0 +1,223 @@
+//! the `watch` subcommand, rebuilds and reruns the selected days whenever their files change
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{anyhow, Context};
+use cli_table::{format::Justify, Color, Table, WithTitle};
+use serde::Deserialize;
+
+use crate::cli::Selection;
+use crate::get_input::InputCache;
+use crate::types::{DateProvider, Solution};
+
+/// how often the files are checked for changes
+const POLL: Duration = Duration::from_millis(500);
+
+/// a result as `--format json` prints it, only the fields the diff needs
+#[derive(Deserialize)]
+struct Row {
+    year: u16,
+    day: u8,
+    label: String,
+    avg_ns: u64,
+    result: serde_json::Value,
+    status: String,
+    error: Option<String>,
+}
+
+impl Row {
+    fn result(&self) -> String {
+        match (&self.result, &self.error) {
+            (_, Some(err)) => format!("failed: {err}"),
+            (serde_json::Value::String(text), _) => text.clone(),
+            (serde_json::Value::Null, _) => "-".to_owned(),
+            (value, _) => value.to_string(),
+        }
+    }
+}
+
+#[derive(Table)]
+struct WatchRow {
+    #[table(title = "year", justify = "Justify::Right")]
+    year: u16,
+    #[table(title = "day")]
+    day: u8,
+    #[table(title = "label")]
+    label: String,
+    #[table(title = "avg", display_fn = "display_duration")]
+    avg: Duration,
+    #[table(title = "result", color = "Color::Green")]
+    result: String,
+    #[table(title = "check")]
+    status: String,
+    #[table(title = "change", color = "Color::Yellow")]
+    change: String,
+}
+
+fn display_duration(inp: &Duration) -> impl std::fmt::Display {
+    format!("{inp:?}")
+}
+
+/// reruns the selection after every change of the sources of its days, their cached inputs,
+/// or the files in `./inputs`, until it is stopped
+pub fn run(
+    days: &'static [Solution],
+    select: &Selection,
+    cache: &InputCache,
+) -> anyhow::Result<()> {
+    // read before the first build replaces the binary
+    let exe = std::env::current_exe().context("failed to find the running binary")?;
+
+    let mut dates = select
+        .select(days)
+        .iter()
+        .map(|it| it.info.get_datetuple())
+        .collect::<Vec<_>>();
+    dates.dedup();
+    if dates.is_empty() {
+        anyhow::bail!("No Matches found!");
+    }
+
+    let mut previous = HashMap::new();
+    let mut stamps = modified(&watched_files(&dates, cache));
+    loop {
+        rerun(&exe, select, &mut previous)?;
+        eprintln!("watching {} files, stop with ctrl-c", stamps.len());
+
+        let changed = loop {
+            thread::sleep(POLL);
+            let next = modified(&watched_files(&dates, cache));
+            let changed = next
+                .iter()
+                .filter(|it| !stamps.contains(it))
+                .map(|(path, _)| path.display().to_string())
+                .collect::<Vec<_>>();
+            if !changed.is_empty() || next.len() != stamps.len() {
+                stamps = next;
+                break changed;
+            }
+        };
+        eprintln!("\nchanged: {}", changed.join(", "));
+    }
+}
+
+/// the sources and cached inputs of the days, and everything in `./inputs`
+fn watched_files(dates: &[(u16, u8)], cache: &InputCache) -> Vec<PathBuf> {
+    let mut files = dates
+        .iter()
+        .flat_map(|&(year, day)| {
+            let mut files = day_sources(year, day);
+            files.push(cache.path(year, day));
+            files
+        })
+        .collect::<Vec<_>>();
+
+    if let Ok(entries) = fs::read_dir("inputs") {
+        files.extend(entries.filter_map(|it| Some(it.ok()?.path())));
+    }
+    files
+}
+
+/// the sources of a day, named `day<day>.rs` with or without leading zeros like in `build.rs`
+fn day_sources(year: u16, day: u8) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(format!("src/yr{year}")) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|it| {
+            let path = it.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let digits = name
+                .strip_prefix("day")?
+                .strip_suffix(".rs")
+                .filter(|it| it.bytes().all(|it| it.is_ascii_digit()))?;
+            (digits.parse::<u8>().ok()? == day).then_some(path)
+        })
+        .collect()
+}
+
+/// when each file was modified, `None` for missing files
+fn modified(files: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    files
+        .iter()
+        .map(|path| {
+            (
+                path.clone(),
+                fs::metadata(path).and_then(|it| it.modified()).ok(),
+            )
+        })
+        .collect()
+}
+
+/// builds the binary again, with the profile of the running one
+fn build() -> anyhow::Result<bool> {
+    let mut cargo = Command::new("cargo");
+    cargo.args(["build", "--bin", env!("CARGO_PKG_NAME")]);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    Ok(cargo
+        .status()
+        .context("failed to run cargo build")?
+        .success())
+}
+
+/// runs the parts and the examples of the selection in the rebuilt binary,
+/// and prints their results with the changes since the last run
+fn rerun(
+    exe: &Path,
+    select: &Selection,
+    previous: &mut HashMap<(u16, u8, String), String>,
+) -> anyhow::Result<()> {
+    if !build()? {
+        eprintln!("the build failed");
+        return Ok(());
+    }
+
+    let mut rows = Vec::new();
+    for args in [&["run", "--no-record"][..], &["test"]] {
+        let output = Command::new(exe)
+            .args(args)
+            .args(["--format", "json"])
+            .args(select.to_args())
+            .stderr(Stdio::inherit())
+            .output()
+            .with_context(|| format!("failed to run {}", exe.display()))?;
+
+        // nothing is printed when the command fails before running anything
+        if let Ok(printed) = serde_json::from_slice::<Vec<Row>>(&output.stdout) {
+            rows.extend(printed);
+        }
+    }
+
+    let changes = rows
+        .into_iter()
+        .map(|row| {
+            let result = row.result();
+            let key = (row.year, row.day, row.label.clone());
+            let change = match previous.insert(key, format!("{result} {}", row.status)) {
+                None => "new".to_owned(),
+                Some(old) if old == format!("{result} {}", row.status) => String::new(),
+                Some(old) => format!("was {old}"),
+            };
+
+            WatchRow {
+                year: row.year,
+                day: row.day,
+                label: row.label,
+                avg: Duration::from_nanos(row.avg_ns),
+                result,
+                status: row.status,
+                change,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    cli_table::print_stdout(changes.with_title()).map_err(|_| anyhow!("Failed to print table"))
+}
@@ -3,7 +3,8 @@ use std::{
     io::{BufRead, BufReader},
 };
 
-use aoc_any::{BenchTimes, Info, ProblemResult, Run, Solution};
+use anyhow::Context;
+use aoc_any::{BenchTimes, Info, Run, Solution};
 
 pub const SOLUTION: Solution = Solution {
     info: Info {
@@ -12,22 +13,36 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: BenchTimes::Many(10),
     },
-    part1: |_| 66_186.into(),
-    part2: Some(|_| 196_804.into()),
+    part1: |data| Ok(elf_calories(data)?.into_iter().max().unwrap_or_default().into()),
+    part2: Some(|data| {
+        let mut elves = elf_calories(data)?;
+        elves.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(elves.iter().take(3).sum::<u32>().into())
+    }),
+    parse: None,
+    params: &[],
+    examples: &[],
     other: &[
-        (
-            "part1 heavy",
-            |_| ProblemResult::Other(Box::new(biginp())),
-            Run::No,
-        ),
-        (
-            "part2 heavy",
-            |_| ProblemResult::Other(Box::new(biginp2())),
-            Run::No,
-        ),
+        ("part1 heavy", |_| Ok(biginp().into()), Run::No),
+        ("part2 heavy", |_| Ok(biginp2().into()), Run::No),
     ],
 };
 
+fn elf_calories(data: &str) -> anyhow::Result<Vec<u32>> {
+    data.split("\n\n")
+        .map(|elf| {
+            elf.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(|line| {
+                    line.parse::<u32>()
+                        .with_context(|| format!("invalid calories {line:?}"))
+                })
+                .sum()
+        })
+        .collect()
+}
+
 fn biginp() -> u32 {
     let mut reader =
         BufReader::new(std::fs::File::open("inputs/aoc_2022_day01_large_input.txt").unwrap());
@@ -1,10 +1,9 @@
 use std::fmt::Formatter;
-use std::hint::black_box;
 use std::{convert::Into, str::FromStr};
 
 use ndarray::Array2;
 
-use aoc_any::{BenchTimes, Info, ProblemResult, Run, Solution};
+use aoc_any::{BenchTimes, Example, Info, ProblemResult, Run, Solution};
 
 pub const SOLUTION: Solution = Solution {
     info: Info {
@@ -13,33 +12,24 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: BenchTimes::Once,
     },
-    part1: |data| do_part1(data).unwrap().into(),
-    part2: Some(|data| {
-        ProblemResult::Other({
-            let _ = black_box(do_part2(data));
-            Box::new(())
-        })
-    }),
-    other: &[
-        (
-            "part1 example",
-            |_| do_part1(TEST_DATA).unwrap().into(),
-            Run::No,
-        ),
-        (
-            "part2 printed",
-            |data| {
-                ProblemResult::Other({
-                    eprintln!("{}", do_part2(data).unwrap());
-                    Box::new(())
-                })
-            },
-            Run::No,
-        ),
-    ],
+    part1: |data| Ok(do_part1(data)?.into()),
+    part2: Some(|data| Ok(aoc_any::ocr::read(&do_part2(data)?)?.into())),
+    parse: None,
+    params: &[],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "13140")],
+        params: &[],
+    }],
+    other: &[(
+        "part2 screen",
+        |data| Ok(ProblemResult::lines(&do_part2(data)?)),
+        Run::No,
+    )],
 };
 
-const TEST_DATA: &str = include_str!("../inputs/day10-test.txt");
+const EXAMPLE: &str = include_str!("../../inputs/day10-test.txt");
 
 fn do_part1(data: &str) -> anyhow::Result<i32> {
     let instructions = parse(data)?;
@@ -1,9 +1,8 @@
-use std::{cell::RefCell, convert::Into, sync::OnceLock};
+use std::{cell::RefCell, convert::Into, sync::LazyLock};
 
-use once_cell::sync::Lazy;
 use regex::Regex;
 
-use aoc_any::{Info, Solution};
+use aoc_any::{Example, Info, Solution};
 use ops::Op;
 
 pub const SOLUTION: Solution = Solution {
@@ -13,17 +12,25 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: aoc_any::BenchTimes::None,
     },
-    part1: |data| part1(data).into(),
-    part2: Some(|data| part2(data).into()),
+    part1: |data| Ok(part1(data, data.param("part1_rounds")?).into()),
+    part2: Some(|data| Ok(part2(data, data.param("part2_rounds")?).into())),
+    parse: None,
+    params: &[("part1_rounds", "20"), ("part2_rounds", "10000")],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "10605"), ("part2", "2713310158")],
+        params: &[],
+    }],
     other: &[],
 };
 
-const _TEST_EXAMPLE: &str = include_str!("../inputs/2022-day11-test.txt");
+const EXAMPLE: &str = include_str!("../../inputs/2022-day11-test.txt");
 
-fn part1(data: &str) -> u64 {
+fn part1(data: &str, rounds: u32) -> u64 {
     let parsed = parse(data);
 
-    for _ in 0..20 {
+    for _ in 0..rounds {
         do_round(&parsed);
     }
 
@@ -38,11 +45,12 @@ fn part1(data: &str) -> u64 {
     parsed.into_iter().rev().take(2).product()
 }
 
-fn part2(data: &str) -> u64 {
+fn part2(data: &str, rounds: u32) -> u64 {
     let parsed = parse(data);
 
-    for _ in 1..=10_000 {
-        do_round2(&parsed);
+    let divisor_product = parsed.iter().map(|it| it.borrow().test.0).product();
+    for _ in 1..=rounds {
+        do_round2(&parsed, divisor_product);
     }
 
     let mut parsed = parsed
@@ -55,11 +63,7 @@ fn part2(data: &str) -> u64 {
     parsed.into_iter().rev().take(2).product()
 }
 
-fn do_round2(parsed: &Vec<RefCell<Monkey>>) {
-    static DIV_PROD: OnceLock<u64> = OnceLock::new();
-    let divisor_product: &u64 =
-        DIV_PROD.get_or_init(|| parsed.iter().map(|it| it.borrow().test.0).product());
-
+fn do_round2(parsed: &Vec<RefCell<Monkey>>, divisor_product: u64) {
     let mut monkey_items;
     for monkey in parsed {
         // take the items from the monkey
@@ -127,7 +131,7 @@ struct Test(u64);
 
 impl Test {
     const fn check(self, and: u64) -> bool {
-        and % self.0 == 0
+        and.is_multiple_of(self.0)
     }
 }
 
@@ -160,8 +164,8 @@ fn parse_monkey(data: &str) -> Monkey {
     };
 
     let operation = {
-        static RE: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"(old|\d{1,2}) ([+*]) (old|\d{1,2})").unwrap());
+        static RE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"(old|\d{1,2}) ([+*]) (old|\d{1,2})").unwrap());
 
         let line = lines.next().unwrap();
 
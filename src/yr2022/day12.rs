@@ -5,7 +5,7 @@ use std::fmt::{Debug, Formatter};
 use gxhash::GxHashSet;
 use ndarray::prelude::*;
 
-use aoc_any::{BenchTimes, Info, Run, Solution};
+use aoc_any::{BenchTimes, Example, Info, Run, Solution};
 use tinyvec::{array_vec, ArrayVec};
 
 pub const SOLUTION: Solution = Solution {
@@ -15,26 +15,31 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: BenchTimes::Default,
     },
-    part1: |data| part1(data).into(),
-    part2: Some(|data| part2(data).into()),
+    part1: |data| Ok(part1(data).into()),
+    part2: Some(|data| Ok(part2(data).into())),
+    parse: None,
+    params: &[],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "31"), ("part2", "29"), ("recursive part2", "29")],
+        params: &[],
+    }],
     other: &[(
         "recursive part2",
         |data| {
             let (data, start_point) = parse::<true>(data);
-            bfs2(
-                &data,
-                VecDeque::from([QueuedPoint {
-                    pos: start_point,
-                    dist: 0,
-                }]),
-            )
-            .into()
+            let start = VecDeque::from([QueuedPoint {
+                pos: start_point,
+                dist: 0,
+            }]);
+            Ok(bfs2(&data, start).into())
         },
         Run::No,
     )],
 };
 
-const _EXAMPLE: &str = "Sabqponm
+const EXAMPLE: &str = "Sabqponm
 abcryxxl
 accszExk
 acctuvwj
@@ -130,9 +135,8 @@ fn loop_bfs(data: &Array2<Point>, mut queue: VecDeque<QueuedPoint>) -> Option<Qu
                 break 'outer_loop Some(node);
             }
 
-            if !visited.contains(&node.pos) {
+            if visited.insert(node.pos) {
                 queue.push_back(node.clone());
-                visited.insert(node.pos);
             }
         }
     }
@@ -153,9 +157,8 @@ fn loop_bfs_part2(data: &Array2<Point>, mut queue: VecDeque<QueuedPoint>) -> u32
                 break 'outer min_dist;
             }
 
-            if !visited.contains(&node.pos) {
+            if visited.insert(node.pos) {
                 queue.push_back(node.clone());
-                visited.insert(node.pos);
             }
         }
     }
@@ -177,9 +180,8 @@ fn bfs2(data: &Array2<Point>, queue: VecDeque<QueuedPoint>) -> u32 {
                         return min_dist.min(node.dist);
                     }
 
-                    if !visited.contains(&node.pos) {
+                    if visited.insert(node.pos) {
                         queue.push_back(node.clone());
-                        visited.insert(node.pos);
                     }
                 }
 
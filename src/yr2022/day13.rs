@@ -1,5 +1,5 @@
-use aoc_any::{BenchTimes, Info, Solution};
-use parse::{Packet, Value};
+use aoc_any::{BenchTimes, Example, Info, Solution};
+use parse::Packet;
 
 pub const SOLUTION: Solution = Solution {
     info: Info {
@@ -8,12 +8,20 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: BenchTimes::Many(89),
     },
-    part1: |data| part1(data).into(),
-    part2: Some(|data| part2(data).into()),
+    part1: |data| Ok(part1(data).into()),
+    part2: Some(|data| Ok(part2(data).into())),
+    parse: None,
+    params: &[],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "13"), ("part2", "140")],
+        params: &[],
+    }],
     other: &[],
 };
 
-const _EXAMPLE: &str = include_str!("../inputs/day13-test.txt");
+const EXAMPLE: &str = include_str!("../../inputs/day13-test.txt");
 
 fn part1(data: &str) -> usize {
     parse::part1(data)
@@ -51,8 +59,8 @@ mod cmp_packet {
             (Value::Num(l), Value::Num(r)) => l.cmp(r),
 
             (Value::List(l), Value::List(r)) => cmp_lists_inner(l, r),
-            (Value::Num(_), Value::List(r)) => cmp_lists_inner(&[lhs.clone()], r), // clone is fine because its only a u32
-            (Value::List(l), Value::Num(_)) => cmp_lists_inner(l, &[rhs.clone()]),
+            (Value::Num(_), Value::List(r)) => cmp_lists_inner(std::slice::from_ref(lhs), r),
+            (Value::List(l), Value::Num(_)) => cmp_lists_inner(l, std::slice::from_ref(rhs)),
         }
     }
 
@@ -61,7 +69,7 @@ mod cmp_packet {
         for either in l.iter().zip_longest(r.iter()) {
             match either {
                 EitherOrBoth::Both(l, r) => match cmp_inner([l, r]) {
-                    cmp::Ordering::Equal => continue,
+                    cmp::Ordering::Equal => {}
                     r#else => return r#else,
                 },
                 EitherOrBoth::Left(_) => return cmp::Ordering::Greater,
@@ -79,6 +87,7 @@ mod cmp_packet {
     /// internally uses a `cmp::Ordering`.
     /// If the Ordering is Greater, the packet is in the wrong order
     /// If the Ordering is Less, the packet is in the right order
+    #[allow(unused)]
     pub fn true_orders([lhs, rhs]: [Packet; 2]) -> bool {
         lhs <= rhs
     }
@@ -109,7 +118,8 @@ mod cmp_packet {
 mod parse {
     use std::{hint::unreachable_unchecked, iter::Peekable};
 
-    use once_cell::sync::Lazy;
+    use std::sync::LazyLock;
+
     use regex::Regex;
 
     #[derive(PartialEq, Eq, Clone, Copy)]
@@ -156,7 +166,7 @@ mod parse {
     #[derive(Clone, PartialEq, Eq)]
     pub enum Value {
         Num(u32),
-        List(Box<[Value]>),
+        List(Box<[Self]>),
     }
 
     pub fn part2(data: &str) -> Vec<Packet> {
@@ -178,7 +188,7 @@ mod parse {
     }
 
     fn parse_line(line: &str) -> Packet {
-        static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[|\]|\d+").unwrap());
+        static RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[|\]|\d+").unwrap());
 
         let tokens = RE
             .find_iter(line)
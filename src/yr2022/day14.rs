@@ -1,9 +1,5 @@
-use std::iter;
+use aoc_any::{BenchTimes, Example, Info, Solution};
 
-use aoc_any::{BenchTimes, Info, Solution};
-use gxhash::GxHashMap;
-
-use itertools::Itertools;
 use parse::{Point, Tile};
 
 pub const SOLUTION: Solution = Solution {
@@ -13,24 +9,42 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: BenchTimes::None,
     },
-    part1: |_data| part1(_data).into(),
-    part2: Some(|_data| part2(_data).into()),
+    part1: |data| Ok(part1(data.parsed(), data.param("origin")?).into()),
+    part2: Some(|data| Ok(part2(data.parsed(), data.param("origin")?).into())),
+    parse: Some(|data| Ok(Box::new(parse::part1(data)))),
+    // where the sand comes from
+    params: &[("origin", "500,0")],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "24"), ("part2", "93")],
+        params: &[],
+    }],
     other: &[],
 };
 
-const EXAMPLE: &str = include_str!("../inputs/2022-day14-test.txt");
+const EXAMPLE: &str = include_str!("../../inputs/2022-day14-test.txt");
 
-fn part1(data: &str) -> u32 {
-    let (map, deepest) = parse::part1(data);
+fn part1((map, deepest): &(parse::Map, u32), origin: Point) -> u32 {
+    let (map, deepest) = (map.clone(), *deepest);
 
-    FallingSand { map, deepest }.count() as u32
+    FallingSand {
+        map,
+        deepest,
+        origin,
+    }
+    .count() as u32
 }
 
-fn part2(data: &str) -> u32 {
-    let (map, deepest) = parse::part1(data);
+fn part2((map, deepest): &(parse::Map, u32), origin: Point) -> u32 {
+    let (map, deepest) = (map.clone(), *deepest);
     // let deepest = deepest - 1;
 
-    let mut falling_sand = FallingSand { map, deepest };
+    let mut falling_sand = FallingSand {
+        map,
+        deepest,
+        origin,
+    };
 
     let mut i = 0;
 
@@ -42,6 +56,7 @@ fn part2(data: &str) -> u32 {
     i
 }
 
+#[allow(unused)]
 fn print_map(map: &parse::Map) {
     let Point { x: max_x, y: max_y } = dbg!(map.keys().max().unwrap());
     let Point { x: min_x, y: _ } = dbg!(map.keys().min().unwrap());
@@ -61,6 +76,7 @@ fn print_map(map: &parse::Map) {
 struct FallingSand {
     map: parse::Map,
     deepest: u32,
+    origin: Point,
 }
 
 impl Iterator for FallingSand {
@@ -73,11 +89,11 @@ impl Iterator for FallingSand {
 
 impl FallingSand {
     fn origin_blocked(&self) -> bool {
-        self.map.contains_key(&Point { x: 500, y: 0 })
+        self.map.contains_key(&self.origin)
     }
 
     fn add_sand(&mut self, part2: bool) -> Result<(), ()> {
-        let mut sand = Point { x: 500, y: 0 };
+        let mut sand = self.origin;
 
         loop {
             if part2 {
@@ -134,7 +150,7 @@ impl FallingSand {
 
 mod parse {
 
-    use std::{collections::BTreeMap, hash::Hash, str::FromStr};
+    use std::{hash::Hash, str::FromStr};
 
     use anyhow::Context;
 
@@ -210,7 +226,9 @@ mod parse {
         }
     }
 
+    #[derive(Clone, Copy)]
     pub enum Tile {
+        #[allow(unused)]
         Empty,
         Rock,
         Sand,
@@ -254,7 +272,7 @@ mod parse {
                     horizontal_map
                         .entry(from.y)
                         .and_modify(|it| it.push(y))
-                        .or_insert(tiny_vec!([[u32; 2]; 4] => y));
+                        .or_insert_with(|| tiny_vec!([[u32; 2]; 4] => y));
                 }
             }
         }
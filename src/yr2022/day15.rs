@@ -1,8 +1,8 @@
-use std::{num::ParseIntError, thread};
+use std::num::ParseIntError;
 
 use anyhow::anyhow;
+use aoc_any::{BenchTimes, Example, Info, Solution};
 use itertools::Itertools;
-use aoc_any::{BenchTimes, Info, Solution};
 use math::Range;
 
 pub const SOLUTION: Solution = Solution {
@@ -12,14 +12,23 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: BenchTimes::None,
     },
-    part1: |data| part1(parse(if TEST { EXAMPLE } else { data }).unwrap()).into(),
+    part1: |data| {
+        let row = data.param("row")?;
+        Ok(part1(parse(data, row)?, row).into())
+    },
     part2: None,
+    parse: None,
+    // the row part 1 counts the positions of, the example asks about row 10
+    params: &[("row", "2000000")],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "26")],
+        params: &[("row", "10")],
+    }],
     other: &[],
 };
 
-const TEST: bool = false;
-const HEIGHT: i64 = if TEST { 10 } else { 2_000_000 };
-
 mod math {
 
     #[derive(Debug, PartialEq, Eq, Clone)]
@@ -32,9 +41,9 @@ mod math {
         pub const fn intersects(&self, other: &Self) -> bool {
             // if sorted in non-descending order
             if self.from >= other.from {
-                self.to >= other.from
+                self.from <= other.to
             } else {
-                other.to >= self.from
+                other.from <= self.to
             }
         }
 
@@ -59,6 +68,7 @@ mod math {
     }
 
     impl super::Line {
+        #[allow(unused)]
         pub const fn new(sensor: super::Point, closest_beacon: super::Point) -> Self {
             Self {
                 sensor,
@@ -66,9 +76,9 @@ mod math {
             }
         }
 
-        pub fn width_at_height(&self) -> Option<Range> {
+        pub fn width_at_height(&self, height: i64) -> Option<Range> {
             let radius = self.sensor.manhattan_distance(&self.closest_beacon);
-            let height_diff = (super::HEIGHT - self.sensor.y).abs();
+            let height_diff = (height - self.sensor.y).abs();
 
             (height_diff <= radius).then(|| {
                 let half_width = radius - height_diff;
@@ -90,7 +100,7 @@ mod math {
                 sensor: Point { x: 8, y: 7 },
                 closest_beacon: Point { x: 2, y: 10 },
             }
-            .width_at_height(),
+            .width_at_height(10),
             Some((2..14).into())
         );
     }
@@ -141,14 +151,14 @@ fn test_part1() {
     assert_eq!(flatten_spanned_len(data), 9);
 }
 
-fn part1((data, sub): Parsed) -> i64 {
+fn part1((data, sub): Parsed, row: i64) -> i64 {
     fn cmp<T: std::cmp::Ord>(a: &T, b: &T) -> std::cmp::Ordering {
         a.cmp(b)
     }
 
     let mut ranges = data
         .iter()
-        .filter_map(Line::width_at_height)
+        .filter_map(|it| it.width_at_height(row))
         .collect::<Vec<_>>();
 
     ranges.sort_by(|a, b| cmp(&a.from, &b.from).then(cmp(&a.to, &b.to)));
@@ -166,46 +176,42 @@ fn flatten_spanned_len(ranges: Vec<Range>) -> i64 {
     }
 
     let mut iter = ranges.into_iter();
-    let mut sum = 0;
-    loop {
-        let mut state = match iter.next() {
-            None => break,
-            Some(state) => state,
-        };
-
-        for el in iter.by_ref() {
-            if !state.intersects(&el) {
-                sum += state.spanned();
-                continue;
-            }
+    let Some(mut state) = iter.next() else {
+        return 0;
+    };
 
-            state = Range {
-                from: min(el.from, state.from),
-                to: max(state.to, el.to),
-            };
+    let mut sum = 0;
+    for el in iter {
+        if !state.intersects(&el) {
+            sum += state.spanned();
+            state = el;
+            continue;
         }
 
-        sum += state.spanned();
+        state = Range {
+            from: min(el.from, state.from),
+            to: max(state.to, el.to),
+        };
     }
 
-    sum
+    sum + state.spanned()
 }
 
-fn parse(data: &str) -> anyhow::Result<Parsed> {
+fn parse(data: &str, row: i64) -> anyhow::Result<Parsed> {
     let res: Vec<_> = data
         .lines()
         .map(|it| {
-            let mut spl = it.split(":").map(|it| -> Result<_, ParseIntError> {
-                let v = it.split("=").skip(1).collect_vec();
+            let mut spl = it.split(':').map(|it| -> Result<_, ParseIntError> {
+                let v = it.split('=').skip(1).collect_vec();
                 Ok([v[0][..v[0].len() - 3].parse::<i64>()?, v[1].parse::<i64>()?])
             });
 
             Ok(Line {
-                closest_beacon: {
+                sensor: {
                     let n = spl.next().unwrap()?;
                     Point { x: n[0], y: n[1] }
                 },
-                sensor: {
+                closest_beacon: {
                     let n = spl.next().unwrap()?;
                     Point { x: n[0], y: n[1] }
                 },
@@ -216,7 +222,7 @@ fn parse(data: &str) -> anyhow::Result<Parsed> {
 
     let x = res
         .iter()
-        .filter_map(|it| (it.closest_beacon.y == HEIGHT).then_some(&it.closest_beacon))
+        .filter_map(|it| (it.closest_beacon.y == row).then_some(&it.closest_beacon))
         .dedup()
         .count();
 
@@ -9,9 +9,12 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: BenchTimes::Default,
     },
+    params: &[],
+    examples: &[],
     other: &[],
-    part1: |data| part1(data).into(),
-    part2: Some(|data| part2(data).into()),
+    part1: |data| Ok(part1(data).into()),
+    part2: Some(|data| Ok(part2(data).into())),
+    parse: None,
 };
 
 const GROUP_SIZE: usize = 3;
@@ -32,7 +35,7 @@ pub fn part1(data: &str) -> u32 {
         .sum()
 }
 
-fn parse_2(data: &str) -> Vec<Group> {
+fn parse_2(data: &str) -> Vec<Group<'_>> {
     data.lines()
         .chunks(GROUP_SIZE)
         .into_iter()
@@ -50,7 +53,7 @@ fn l_r_diff(data: &Data1) -> char {
     *set1.intersection(&set2).next().unwrap()
 }
 
-fn parse_1(data: &str) -> impl Iterator<Item = Data1> {
+fn parse_1(data: &str) -> impl Iterator<Item = Data1<'_>> {
     data.lines().map(|line| {
         let (left, right) = line.split_at(line.len() / 2);
         Data1 { left, right }
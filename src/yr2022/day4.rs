@@ -1,5 +1,5 @@
-use anyhow::Ok;
-use aoc_any::{BenchTimes, ProblemResult};
+use anyhow::{Context, Ok};
+use aoc_any::{BenchTimes, Example, ProblemResult};
 use std::cmp::{max, min};
 use std::str::FromStr;
 
@@ -10,11 +10,21 @@ pub const SOLUTION: aoc_any::Solution = aoc_any::Solution {
         year: 2022,
         bench: BenchTimes::Many(100),
     },
-    part1: |data| ProblemResult::Number(i64::from(part1(data).unwrap())),
-    part2: Some(|data| ProblemResult::Number(i64::from(part2(data).unwrap()))),
+    part1: |data| Ok(ProblemResult::Number(i64::from(part1(data)?))),
+    part2: Some(|data| Ok(ProblemResult::Number(i64::from(part2(data)?)))),
+    parse: None,
+    params: &[],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "2"), ("part2", "4")],
+        params: &[],
+    }],
     other: &[],
 };
 
+const EXAMPLE: &str = include_str!("../../inputs/day4-test.txt");
+
 fn part1_withdata(data: &str) -> anyhow::Result<u32> {
     let parsed = parse1(data)?;
     Ok(parsed.iter().filter(|r2| r2.contains_self()).count() as u32)
@@ -67,7 +77,10 @@ impl FromStr for Range2 {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let ranges: [&str; 2] = s.split_once(',').unwrap().into();
+        let ranges: [&str; 2] = s
+            .split_once(',')
+            .with_context(|| format!("no ',' in {s:?}"))?
+            .into();
         let [fst, snd]: [_; 2] = ranges
             .iter()
             .map(|range| range.parse::<Range>())
@@ -87,7 +100,9 @@ struct Range {
 impl FromStr for Range {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s.split_once('-').unwrap();
+        let (start, end) = s
+            .split_once('-')
+            .with_context(|| format!("no '-' in {s:?}"))?;
         Ok(Self {
             start: start.parse()?,
             end: end.parse()?,
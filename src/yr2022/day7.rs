@@ -1,7 +1,7 @@
 use std::{collections::HashMap, vec};
 
 use anyhow::Ok;
-use aoc_any::{Info, ProblemResult, Solution};
+use aoc_any::{Example, Info, Solution};
 
 pub const SOLUTION: Solution = Solution {
     info: Info {
@@ -10,21 +10,31 @@ pub const SOLUTION: Solution = Solution {
         name: "No Space Left On Device",
         year: 2022,
     },
+    params: &[],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "95437"), ("part2", "24933642")],
+        params: &[],
+    }],
     other: &[],
-    part1: |data| ProblemResult::Number(part1(data).try_into().unwrap()),
-    part2: Some(|data| ProblemResult::Number(part2(data).try_into().unwrap())),
+    part1: |data| Ok(part1(data)?.into()),
+    part2: Some(|data| Ok(part2(data)?.into())),
+    parse: None,
 };
 
-pub fn part1(data: &str) -> u64 {
-    let parsed = parse(data).unwrap();
+const EXAMPLE: &str = include_str!("../../inputs/day7-test.txt");
+
+pub fn part1(data: &str) -> anyhow::Result<u64> {
+    let parsed = parse(data)?;
     let map = build_treemap(parsed);
-    sum_dir_size_under(&map, 100_000)
+    Ok(sum_dir_size_under(&map, 100_000))
 }
 
-pub fn part2(data: &str) -> u64 {
-    let parsed = parse(data).unwrap();
+pub fn part2(data: &str) -> anyhow::Result<u64> {
+    let parsed = parse(data)?;
     let map = build_treemap(parsed);
-    smalles_del_to_free(&map)
+    Ok(smalles_del_to_free(&map))
 }
 
 fn smalles_del_to_free(treemap: &HashMap<Vec<String>, Dir>) -> u64 {
@@ -176,8 +186,7 @@ fn parse_ls(cmd: &str) -> Vec<LsDir> {
             }
 
             let files = lines
-                .enumerate()
-                .map(|(_, line)| {
+                .map(|line| {
                     let (size, name) = line.split_once(' ').unwrap_or_else(|| {
                         //  eprintln!("@l {i}: {line}");
                         panic!();
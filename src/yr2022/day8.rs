@@ -9,7 +9,7 @@ use nd::prelude::*;
 use ndarray as nd;
 use rayon::prelude::*;
 
-use aoc_any::{zip, BenchTimes, Info, ProblemResult, Run};
+use aoc_any::{zip, BenchTimes, Example, Info, Run};
 
 pub const SOLUTION: aoc_any::Solution = aoc_any::Solution {
     info: Info {
@@ -18,18 +18,28 @@ pub const SOLUTION: aoc_any::Solution = aoc_any::Solution {
         year: 2022,
         bench: BenchTimes::Many(100),
     },
-    part1: |data| part1nd(data).into(),
-    part2: Some(|data| ProblemResult::Number(part2(data).try_into().unwrap())),
+    part1: |data| Ok(part1nd(data.parsed()).into()),
+    part2: Some(|data| Ok(part2(data.parsed()).into())),
+    parse: Some(|data| Ok(Box::new(parse_nd(data)))),
+    params: &[],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[("part1", "21"), ("part2", "8"), ("part1 legacy", "21")],
+        params: &[],
+    }],
     other: &[
-        ("part1 legacy", |data| part1(data).into(), Run::No),
+        ("part1 legacy", |data| Ok(part1(data).into()), Run::No),
         (
             "heavy input, 1 + 2",
-            |_| ProblemResult::Other(Box::new(big_inp_1and2())),
+            |_| Ok(big_inp_1and2().into()),
             Run::No,
         ),
     ],
 };
 
+const EXAMPLE: &str = include_str!("../../inputs/day8-test.txt");
+
 #[derive(Clone, PartialEq, Eq)]
 struct TreeVis(u8, bool, Reason);
 
@@ -38,7 +48,7 @@ struct TreeVisNd(u8, bool);
 
 impl std::fmt::Debug for TreeVisNd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{{} {}}}", self.0, if self.1 { "#" } else { "." },)
+        write!(f, "{{{} {}}}", self.0, if self.1 { "#" } else { "." })
     }
 }
 
@@ -48,7 +58,7 @@ enum Reason {
     Vertical,
     HorizontalRev,
     VerticalRev,
-    Two(Box<(Reason, Reason)>),
+    Two(Box<(Self, Self)>),
 }
 
 impl std::fmt::Debug for TreeVis {
@@ -62,7 +72,7 @@ impl std::fmt::Debug for TreeVis {
                 self.2
             )
         } else {
-            write!(f, "[{} {}]", self.0, if self.1 { "#" } else { "." },)
+            write!(f, "[{} {}]", self.0, if self.1 { "#" } else { "." })
         }
     }
 }
@@ -86,8 +96,8 @@ fn do_part1nd(data: Array2<TreeVisNd>) -> u32 {
     }
 }
 
-pub fn part1nd(data: &str) -> u32 {
-    do_part1nd(parse_nd(data))
+fn part1nd(data: &Array2<TreeVisNd>) -> u32 {
+    do_part1nd(data.clone())
 }
 
 pub fn part1(data: &str) -> u32 {
@@ -95,12 +105,12 @@ pub fn part1(data: &str) -> u32 {
     to_visible_treecount(data)
 }
 
-pub fn part2(data: &str) -> usize {
-    max_scenic_score(&parse_nd(data))
+fn part2(data: &Array2<TreeVisNd>) -> usize {
+    max_scenic_score(data)
 }
 
 pub fn big_inp_1and2() -> (u32, usize) {
-    let data = include_str!("../inputs/aoc_2022_day08_sparse.txt");
+    let data = include_str!("../../inputs/aoc_2022_day08_sparse.txt");
 
     let part2_res = if cfg!(debug_assertions) {
         par_max_scenic_score(&parse_nd(data))
@@ -165,7 +175,7 @@ fn par_max_scenic_score(data: &Array2<TreeVisNd>) -> usize {
 }
 
 /// return a view into the array with the outer two rows and colums removed
-fn slice_treevis_nd(data: &Array2<TreeVisNd>) -> ArrayView2<TreeVisNd> {
+fn slice_treevis_nd(data: &Array2<TreeVisNd>) -> ArrayView2<'_, TreeVisNd> {
     data.slice(s![1..-1, 1..-1])
 }
 
@@ -8,7 +8,7 @@ use std::{
 use anyhow::anyhow;
 use gxhash::GxHashSet;
 
-use aoc_any::{set_trait::Set, BenchTimes, Info, Run, Solution};
+use aoc_any::{set_trait::Set, BenchTimes, Example, Info, Run, Solution};
 
 #[rustfmt::skip]
 const EXAMPLE: &str = 
@@ -28,40 +28,49 @@ pub const SOLUTION: Solution = Solution {
         year: 2022,
         bench: BenchTimes::Once,
     },
-    part1: |data| do_part1(parse(data).unwrap(), GxHashSet::default()).into(),
-    part2: Some(|data| part2(data).into()),
+    part1: |data| Ok(do_part1(parse(data)?, GxHashSet::default()).into()),
+    part2: Some(|data| Ok(part2(data)?.into())),
+    parse: None,
+    params: &[],
+    examples: &[Example {
+        name: "example",
+        input: EXAMPLE,
+        answers: &[
+            ("part1", "13"),
+            ("part2", "1"),
+            ("BTreeSet part1", "13"),
+            ("GxHash part1", "13"),
+            ("StdHash part1", "13"),
+        ],
+        params: &[],
+    }],
     other: &[
         (
             "BTreeSet part1",
-            |data| part1_btreeset(data).into(),
+            |data| Ok(part1_btreeset(data)?.into()),
             Run::No,
         ),
         (
             "GxHash part1",
-            |data| do_part1(parse(data).unwrap(), GxHashSet::default()).into(),
+            |data| Ok(do_part1(parse(data)?, GxHashSet::default()).into()),
             Run::No,
         ),
         (
             "StdHash part1",
-            |data| do_part1(parse(data).unwrap(), HashSet::new()).into(),
-            Run::No,
-        ),
-        (
-            "part1 example gxhash",
-            |_| do_part1(parse(EXAMPLE).unwrap(), GxHashSet::default()).into(),
+            |data| Ok(do_part1(parse(data)?, HashSet::new()).into()),
             Run::No,
         ),
     ],
 };
 
-fn part2(data: &str) -> u32 {
-    let data = parse(data).unwrap();
-    do_part2(data, GxHashSet::default())
+fn part2(data: &str) -> anyhow::Result<u32> {
+    let data = parse(data)?;
+    Ok(do_part2(data, GxHashSet::default()))
 }
 
-fn part1_btreeset(data: &str) -> u32 {
-    let data = parse(data).unwrap();
-    do_part1(data, BTreeSet::new())
+fn part1_btreeset(data: &str) -> anyhow::Result<u32> {
+    let data = parse(data)?;
+    Ok(do_part1(data, BTreeSet::new()))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -152,7 +161,7 @@ fn update_tail_match(head: Pos, tail: &mut Pos) {
 }
 
 impl Pos {
-    fn apply(&mut self, dir: &Direction) {
+    const fn apply(&mut self, dir: &Direction) {
         match dir {
             Direction::Up => self.y += 1,
             Direction::Down => self.y -= 1,
@@ -195,5 +204,5 @@ impl FromStr for Direction {
 }
 
 // const fn get_data() -> &'static str {
-//     include_str!("../inputs/day9-inp.txt")
+//     include_str!("../../inputs/day9-inp.txt")
 // }